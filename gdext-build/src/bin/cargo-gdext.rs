@@ -0,0 +1,130 @@
+//! `cargo gdext`: scaffolds, builds and installs extension crates.
+//!
+//! ```text
+//! cargo gdext new <dir> [--gdext-path <dir>]
+//! cargo gdext build --project <godot project dir> [--manifest-path <Cargo.toml>]
+//!                   [--release] [--target <triple>]
+//! ```
+//!
+//! `new` creates a crate with a `gdext_init!` entry point and an example class, depending on the
+//! gdext crates this tool was built from unless `--gdext-path` is given.
+//!
+//! `build` builds the crate, copies the library into `bin/<target>/<profile>` of the Godot
+//! project and regenerates the project's `.gdextension` file loading the library, creating one if
+//! there's none.
+
+use std::{path::PathBuf, process};
+
+use gdext_build::{cargo_build, scaffold, ExtensionCrate, Profile};
+
+const USAGE: &str = "usage:
+    cargo gdext new <dir> [--gdext-path <dir>]
+    cargo gdext build --project <dir> [--manifest-path <Cargo.toml>] [--release] [--target <triple>]";
+
+enum Command {
+    New {
+        dir: PathBuf,
+        gdext_path: PathBuf,
+    },
+    Build {
+        project: PathBuf,
+        manifest_path: PathBuf,
+        profile: Profile,
+        target: Option<String>,
+    },
+}
+
+fn parse_args() -> Result<Command, String> {
+    // Invoked as `cargo-gdext gdext <args>` by cargo, or directly as `cargo-gdext <args>`.
+    let mut args = std::env::args().skip(1).peekable();
+    if args.peek().map(String::as_str) == Some("gdext") {
+        args.next();
+    }
+
+    let command = args.next().ok_or("missing command")?;
+    let mut positional = Vec::new();
+    let mut options = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--release" => options.push((arg, String::new())),
+            _ if arg.starts_with("--") => {
+                let value = args.next().ok_or(format!("missing value for `{arg}`"))?;
+                options.push((arg, value));
+            }
+            _ => positional.push(arg),
+        }
+    }
+    let option = |name: &str| {
+        options
+            .iter()
+            .find(|(option, _)| option == name)
+            .map(|(_, value)| value.clone())
+    };
+    if let Some((unknown, _)) = options.iter().find(|(option, _)| {
+        ![
+            "--gdext-path",
+            "--project",
+            "--manifest-path",
+            "--release",
+            "--target",
+        ]
+        .contains(&option.as_str())
+    }) {
+        return Err(format!("unexpected argument `{unknown}`"));
+    }
+
+    match command.as_str() {
+        "new" => {
+            let dir = match positional.as_slice() {
+                [dir] => PathBuf::from(dir),
+                _ => return Err("expected exactly one directory".to_string()),
+            };
+            let gdext_path = option("--gdext-path")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/..")));
+            Ok(Command::New { dir, gdext_path })
+        }
+        "build" => Ok(Command::Build {
+            project: option("--project").ok_or("missing `--project`")?.into(),
+            manifest_path: option("--manifest-path")
+                .unwrap_or_else(|| "Cargo.toml".to_string())
+                .into(),
+            profile: match option("--release") {
+                Some(_) => Profile::Release,
+                None => Profile::Debug,
+            },
+            target: option("--target"),
+        }),
+        _ => Err(format!("unknown command `{command}`")),
+    }
+}
+
+fn main() {
+    let command = parse_args().unwrap_or_else(|e| {
+        eprintln!("error: {e}\n\n{USAGE}");
+        process::exit(2);
+    });
+
+    let result = match command {
+        Command::New { dir, gdext_path } => {
+            let gdext_path = gdext_path.canonicalize().unwrap_or(gdext_path);
+            scaffold::scaffold(&dir, &gdext_path)
+                .map(|()| println!("Created extension crate in {}", dir.display()))
+        }
+        Command::Build {
+            project,
+            manifest_path,
+            profile,
+            target,
+        } => ExtensionCrate::from_manifest_path(&manifest_path)
+            .and_then(|library| {
+                cargo_build::build_and_install(&library, &project, profile, target.as_deref())
+            })
+            .map(|installed| println!("Installed {}", installed.display())),
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,68 @@
+//! Generates the `.gdextension` file of an extension crate.
+//!
+//! ```text
+//! gdext-manifest [--manifest-path <Cargo.toml>] [--library-dir <res://...>] [--flat]
+//!                [--output <file.gdextension>]
+//! ```
+//!
+//! Prints the manifest if no output file is given.
+
+use std::{path::PathBuf, process};
+
+use gdext_build::{ExtensionCrate, Manifest, TargetDirLayout};
+
+struct Args {
+    manifest_path: PathBuf,
+    library_dir: Option<String>,
+    layout: TargetDirLayout,
+    output: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        manifest_path: PathBuf::from("Cargo.toml"),
+        library_dir: None,
+        layout: TargetDirLayout::PerTarget,
+        output: None,
+    };
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        let mut value = || iter.next().ok_or(format!("missing value for `{arg}`"));
+        match arg.as_str() {
+            "--manifest-path" => args.manifest_path = value()?.into(),
+            "--library-dir" => args.library_dir = Some(value()?),
+            "--output" => args.output = Some(value()?.into()),
+            "--flat" => args.layout = TargetDirLayout::Flat,
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+    Ok(args)
+}
+
+fn main() {
+    let args = parse_args().unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        process::exit(2);
+    });
+
+    let library = ExtensionCrate::from_manifest_path(&args.manifest_path).unwrap_or_else(|e| {
+        eprintln!("error: {}: {e}", args.manifest_path.display());
+        process::exit(1);
+    });
+
+    let mut manifest = Manifest::new(library).layout(args.layout);
+    if let Some(library_dir) = args.library_dir {
+        manifest = manifest.library_dir(library_dir);
+    }
+
+    match args.output {
+        Some(output) => {
+            if let Err(e) = manifest.write(&output) {
+                eprintln!("error: {}: {e}", output.display());
+                process::exit(1);
+            }
+        }
+        None => print!("{}", manifest.render()),
+    }
+}
@@ -0,0 +1,229 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use crate::{Error, ExtensionCrate, Manifest, Os, Platform, Profile, TargetDirLayout};
+
+/// Where the libraries are copied to in the Godot project, laid out by target and profile.
+pub const PROJECT_LIBRARY_DIR: &str = "bin";
+
+/// Builds the extension crate, copies the library into `<project>/bin/<target>/<profile>` and
+/// regenerates the project's `.gdextension` file loading the library, see
+/// [`find_project_manifest`]. Without one, `<project>/<library name>.gdextension` is created.
+///
+/// `target` is a Rust target triple, the host's if `None`. Returns the path of the copied
+/// library.
+pub fn build_and_install(
+    library: &ExtensionCrate,
+    project: &Path,
+    profile: Profile,
+    target: Option<&str>,
+) -> Result<PathBuf, Error> {
+    let target = match target {
+        Some(target) => target.to_string(),
+        None => host_target()?,
+    };
+    let platform = Platform::ALL
+        .into_iter()
+        .find(|platform| platform.target_triple() == target);
+
+    let artifact = cargo_build(&library.root.join("Cargo.toml"), profile, &target)?;
+
+    let os = match platform {
+        Some(platform) => platform.os,
+        None if target.contains("windows") => Os::Windows,
+        None if target.contains("apple") => Os::MacOs,
+        None => Os::Linux,
+    };
+    let install_dir = project
+        .join(PROJECT_LIBRARY_DIR)
+        .join(&target)
+        .join(profile.name());
+    fs::create_dir_all(&install_dir)?;
+    let installed = install_dir.join(library.library_file_name(os));
+    fs::copy(&artifact, &installed)?;
+
+    let manifest_path = match find_project_manifest(project, library)? {
+        Some(path) => path,
+        None => project.join(format!("{}.gdextension", library.library_name)),
+    };
+    Manifest::new(library.clone())
+        .library_dir(format!("res://{PROJECT_LIBRARY_DIR}"))
+        .layout(TargetDirLayout::PerTarget)
+        .write(&manifest_path)?;
+
+    Ok(installed)
+}
+
+/// Returns the `.gdextension` file in `project` or its subdirectories which loads `library`, if
+/// there is one.
+pub fn find_project_manifest(
+    project: &Path,
+    library: &ExtensionCrate,
+) -> Result<Option<PathBuf>, Error> {
+    let mut manifests = Vec::new();
+    find_manifests(project, library, &mut manifests)?;
+    match manifests.len() {
+        0 => Ok(None),
+        1 => Ok(manifests.pop()),
+        _ => Err(Error::MultipleManifests(manifests)),
+    }
+}
+
+fn find_manifests(
+    dir: &Path,
+    library: &ExtensionCrate,
+    manifests: &mut Vec<PathBuf>,
+) -> Result<(), Error> {
+    let mut entries = fs::read_dir(dir)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.path());
+
+    for entry in entries {
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            // Godot's `.godot` cache, version control and the like.
+            if !entry.file_name().to_string_lossy().starts_with('.') {
+                find_manifests(&path, library, manifests)?;
+            }
+        } else if path.extension().is_some_and(|ext| ext == "gdextension")
+            && loads_library(&fs::read_to_string(&path)?, library)
+        {
+            manifests.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Whether the `.gdextension` file `contents` loads `library`: its entry symbol is the library's,
+/// or one of its library paths names the library's file.
+fn loads_library(contents: &str, library: &ExtensionCrate) -> bool {
+    let file_names = [Os::Linux, Os::Windows, Os::MacOs].map(|os| library.library_file_name(os));
+    contents
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .any(|(key, value)| {
+            let value = value.trim().trim_matches('"');
+            if key.trim() == "entry_symbol" {
+                value == library.entry_symbol
+            } else {
+                let file_name = value.rsplit('/').next().unwrap_or(value);
+                file_names.iter().any(|name| name == file_name)
+            }
+        })
+}
+
+/// Runs `cargo build` and returns the path of the dynamic library it produced.
+fn cargo_build(manifest_path: &Path, profile: Profile, target: &str) -> Result<PathBuf, Error> {
+    let mut command = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+    command
+        .arg("build")
+        .arg("--lib")
+        .arg("--manifest-path")
+        .arg(manifest_path)
+        .arg("--target")
+        .arg(target)
+        .arg("--message-format=json-render-diagnostics")
+        .stdout(Stdio::piped());
+    if profile == Profile::Release {
+        command.arg("--release");
+    }
+
+    let mut child = command.spawn()?;
+    let mut artifact = None;
+
+    for line in BufReader::new(child.stdout.take().unwrap()).lines() {
+        let message = match serde_json::from_str::<serde_json::Value>(&line?) {
+            Ok(message) => message,
+            Err(_) => continue,
+        };
+        if message["reason"] != "compiler-artifact" {
+            continue;
+        }
+
+        let is_cdylib = message["target"]["kind"]
+            .as_array()
+            .is_some_and(|kinds| kinds.iter().any(|kind| kind == "cdylib"));
+        if !is_cdylib {
+            continue;
+        }
+
+        artifact = message["filenames"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|filename| filename.as_str())
+            .find(|filename| {
+                [".so", ".dll", ".dylib"]
+                    .iter()
+                    .any(|ext| filename.ends_with(ext))
+            })
+            .map(PathBuf::from)
+            .or(artifact);
+    }
+
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(Error::Cargo(format!("`cargo build` exited with {status}")));
+    }
+    artifact.ok_or(Error::MissingArtifact)
+}
+
+/// Returns the host's target triple, as reported by `rustc -vV`.
+fn host_target() -> Result<String, Error> {
+    let output = Command::new(std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into()))
+        .arg("-vV")
+        .output()?;
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .find_map(|line| line.strip_prefix("host: "))
+        .map(str::to_string)
+        .ok_or_else(|| Error::Cargo("could not determine the host target".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn library() -> ExtensionCrate {
+        ExtensionCrate {
+            root: PathBuf::from("native"),
+            library_name: "native".to_string(),
+            entry_symbol: "native_init".to_string(),
+        }
+    }
+
+    #[test]
+    fn recognizes_the_entry_symbol() {
+        let contents = "[configuration]\n\
+                        entry_symbol = \"native_init\"\n\
+                        \n\
+                        [libraries]\n\
+                        linux.debug.x86_64 = \"res://lib/renamed.so\"\n";
+        assert!(loads_library(contents, &library()));
+    }
+
+    #[test]
+    fn recognizes_the_library_file() {
+        let contents = "[configuration]\n\
+                        entry_symbol = \"other_init\"\n\
+                        \n\
+                        [libraries]\n\
+                        windows.64 = \"res://../target/debug/native.dll\"\n";
+        assert!(loads_library(contents, &library()));
+    }
+
+    #[test]
+    fn ignores_other_libraries() {
+        let contents = "[configuration]\n\
+                        entry_symbol = \"native_init_2\"\n\
+                        \n\
+                        [libraries]\n\
+                        linux.debug.x86_64 = \"res://bin/libnative_2.so\"\n\
+                        macos.debug.arm64 = \"res://bin/libnative.so.dylib\"\n";
+        assert!(!loads_library(contents, &library()));
+    }
+}
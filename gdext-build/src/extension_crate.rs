@@ -0,0 +1,261 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{Error, Os};
+
+/// What the manifest needs to know about an extension crate.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ExtensionCrate {
+    /// The directory containing the crate's `Cargo.toml`.
+    pub root: PathBuf,
+    /// The name of the library target, which the name of the built library is derived from.
+    pub library_name: String,
+    /// The name passed to `gdext_init!`.
+    pub entry_symbol: String,
+}
+
+impl ExtensionCrate {
+    /// Reads the crate metadata from `Cargo.toml`, and the entry symbol from the `gdext_init!`
+    /// invocation in the crate's sources.
+    pub fn from_manifest_path(manifest_path: &Path) -> Result<Self, Error> {
+        let manifest = fs::read_to_string(manifest_path)?.parse::<toml::Value>()?;
+        let root = manifest_path
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .to_path_buf();
+
+        let lib = manifest.get("lib");
+        let library_name = lib
+            .and_then(|lib| lib.get("name"))
+            .or_else(|| manifest.get("package").and_then(|p| p.get("name")))
+            .and_then(toml::Value::as_str)
+            .ok_or(Error::MissingLibraryName)?
+            .replace('-', "_");
+
+        let lib_path = lib
+            .and_then(|lib| lib.get("path"))
+            .and_then(toml::Value::as_str)
+            .unwrap_or("src/lib.rs");
+        let src_dir = root
+            .join(lib_path)
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| root.join("src"));
+
+        let mut entry_symbols = Vec::new();
+        find_entry_symbols(&src_dir, &mut entry_symbols)?;
+        let entry_symbol = match entry_symbols.len() {
+            0 => return Err(Error::MissingEntrySymbol),
+            1 => entry_symbols.remove(0),
+            _ => return Err(Error::MultipleEntrySymbols(entry_symbols)),
+        };
+
+        Ok(Self {
+            root,
+            library_name,
+            entry_symbol,
+        })
+    }
+
+    /// Returns the file name of the built library on `os`.
+    pub fn library_file_name(&self, os: Os) -> String {
+        match os {
+            Os::Linux => format!("lib{}.so", self.library_name),
+            Os::Windows => format!("{}.dll", self.library_name),
+            Os::MacOs => format!("lib{}.dylib", self.library_name),
+        }
+    }
+}
+
+fn find_entry_symbols(dir: &Path, entry_symbols: &mut Vec<String>) -> Result<(), Error> {
+    let mut entries = fs::read_dir(dir)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.path());
+
+    for entry in entries {
+        let path = entry.path();
+        if path.is_dir() {
+            find_entry_symbols(&path, entry_symbols)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            let source = fs::read_to_string(&path)?;
+            entry_symbols.extend(parse_entry_symbols(&source));
+        }
+    }
+    Ok(())
+}
+
+/// Returns the first argument of every `gdext_init!` invocation outside of comments and string
+/// literals.
+fn parse_entry_symbols(source: &str) -> Vec<String> {
+    const MACRO: &str = "gdext_init!";
+
+    let code = strip_comments_and_literals(source);
+    let mut entry_symbols = Vec::new();
+    let mut start = 0;
+    while let Some(pos) = code[start..].find(MACRO) {
+        let at = start + pos;
+        start = at + MACRO.len();
+
+        // Not the end of a longer name, e.g. `my_gdext_init!`.
+        if code[..at].ends_with(|c: char| c.is_alphanumeric() || c == '_') {
+            continue;
+        }
+
+        let args = code[start..].trim_start();
+        if let Some(args) = args.strip_prefix(['(', '{', '[']) {
+            let name = args
+                .trim_start()
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect::<String>();
+            if !name.is_empty() {
+                entry_symbols.push(name);
+            }
+        }
+    }
+    entry_symbols
+}
+
+/// Replaces comments and the contents of string and character literals with spaces, keeping the
+/// rest of `source` as is.
+fn strip_comments_and_literals(source: &str) -> String {
+    let chars = source.chars().collect::<Vec<_>>();
+    let mut out = String::with_capacity(source.len());
+    let mut i = 0;
+
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let at = |i: usize, s: &str| {
+        s.chars()
+            .enumerate()
+            .all(|(j, c)| chars.get(i + j) == Some(&c))
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        // Whether `r` starts a raw string (or byte string) literal rather than ends a name.
+        let starts_raw = match i.checked_sub(1).map(|j| chars[j]) {
+            Some('b') => i < 2 || !is_ident(chars[i - 2]),
+            Some(prev) => !is_ident(prev),
+            None => true,
+        };
+
+        if at(i, "//") {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if at(i, "/*") {
+            // Block comments nest.
+            let mut depth = 0;
+            while i < chars.len() {
+                if at(i, "/*") {
+                    depth += 1;
+                    i += 2;
+                } else if at(i, "*/") {
+                    depth -= 1;
+                    i += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    i += 1;
+                }
+            }
+            out.push(' ');
+        } else if c == 'r' && starts_raw && matches!(chars.get(i + 1), Some('"' | '#')) {
+            // A raw string, `r"..."` or `r#"..."#` with any number of `#`.
+            let hashes = chars[i + 1..].iter().take_while(|&&c| c == '#').count();
+            if chars.get(i + 1 + hashes) != Some(&'"') {
+                out.push(c);
+                i += 1;
+                continue;
+            }
+            let end = format!("\"{}", "#".repeat(hashes));
+            i += hashes + 2;
+            while i < chars.len() && !at(i, &end) {
+                i += 1;
+            }
+            i += end.len();
+            out.push(' ');
+        } else if c == '"' {
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i += 1;
+            out.push(' ');
+        } else if c == '\'' && chars.get(i + 1) == Some(&'\\') {
+            // An escaped character literal, e.g. `'\''`.
+            i += 2;
+            while i < chars.len() && chars[i] != '\'' {
+                i += 1;
+            }
+            i += 1;
+            out.push(' ');
+        } else if c == '\'' && chars.get(i + 2) == Some(&'\'') {
+            i += 3;
+            out.push(' ');
+        } else {
+            // Including the `'` of lifetimes.
+            out.push(c);
+            i += 1;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_entry_symbols() {
+        let source = "gdext_init!(first, |_| {});\n\
+                      gdext_class::gdext_init! { second, |init| {} }\n\
+                      gdext_init! [ third, init ];";
+        assert_eq!(parse_entry_symbols(source), ["first", "second", "third"]);
+    }
+
+    #[test]
+    fn skips_comments() {
+        let source = "// gdext_init!(line, |_| {});\n\
+                      /* gdext_init!(block, |_| {});\n\
+                      /* nested */ gdext_init!(still_block, |_| {}); */\n\
+                      /// gdext_init!(doc, |_| {});\n\
+                      gdext_init!(real, |_| {}); // gdext_init!(trailing, |_| {});";
+        assert_eq!(parse_entry_symbols(source), ["real"]);
+    }
+
+    #[test]
+    fn skips_string_literals() {
+        let source = r####"
+            let a = "gdext_init!(string, |_| {})";
+            let b = "escaped \" gdext_init!(escaped, |_| {})";
+            let c = r#"gdext_init!(raw, |_| {}) " still raw"#;
+            let d = b"gdext_init!(bytes, |_| {})";
+            let var = "gdext_init!(after_name_ending_in_r, |_| {})";
+            let e = '"';
+            fn f<'a>(_: &'a str) {}
+            gdext_init!(real, |_| {});
+        "####;
+        assert_eq!(parse_entry_symbols(source), ["real"]);
+    }
+
+    #[test]
+    fn ends_raw_strings_at_the_first_quote() {
+        let source = r#"let d = br"\"; gdext_init!(after_raw, |_| {});"#;
+        assert_eq!(parse_entry_symbols(source), ["after_raw"]);
+    }
+
+    #[test]
+    fn skips_other_macros() {
+        let source = "my_gdext_init!(other, |_| {}); gdext_init!(real, |_| {});";
+        assert_eq!(parse_entry_symbols(source), ["real"]);
+    }
+
+    #[test]
+    fn ignores_invocations_without_name() {
+        assert!(parse_entry_symbols("gdext_init!(|_| {});").is_empty());
+        assert!(parse_entry_symbols("macro_rules! gdext_init { () => {} }").is_empty());
+    }
+}
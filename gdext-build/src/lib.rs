@@ -0,0 +1,73 @@
+//! Build helpers for extension crates, used by the `gdext-manifest` and `cargo-gdext` binaries.
+
+use std::{fmt, io, path::PathBuf};
+
+pub mod cargo_build;
+pub mod extension_crate;
+pub mod manifest;
+pub mod scaffold;
+
+pub use extension_crate::ExtensionCrate;
+pub use manifest::{Arch, Manifest, Os, Platform, Profile, TargetDirLayout};
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Toml(toml::de::Error),
+    /// The `Cargo.toml` has neither a `[lib] name` nor a `[package] name`.
+    MissingLibraryName,
+    /// No `gdext_init!` invocation was found in the crate's sources.
+    MissingEntrySymbol,
+    /// `gdext_init!` is invoked more than once, with these names.
+    MultipleEntrySymbols(Vec<String>),
+    /// `cargo` failed, with this message.
+    Cargo(String),
+    /// `cargo build` finished without producing a dynamic library.
+    MissingArtifact,
+    /// Scaffolding would overwrite this file.
+    AlreadyExists(PathBuf),
+    /// Several `.gdextension` files of the Godot project load the library.
+    MultipleManifests(Vec<PathBuf>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{e}"),
+            Self::Toml(e) => write!(f, "invalid Cargo.toml: {e}"),
+            Self::MissingLibraryName => write!(f, "Cargo.toml has no package or library name"),
+            Self::MissingEntrySymbol => write!(f, "no `gdext_init!` invocation found"),
+            Self::MultipleEntrySymbols(names) => write!(
+                f,
+                "`gdext_init!` is invoked more than once: {}",
+                names.join(", ")
+            ),
+            Self::Cargo(message) => write!(f, "cargo failed: {message}"),
+            Self::MissingArtifact => write!(f, "cargo build produced no dynamic library"),
+            Self::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            Self::MultipleManifests(paths) => write!(
+                f,
+                "several .gdextension files load the library: {}",
+                paths
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for Error {
+    fn from(e: toml::de::Error) -> Self {
+        Self::Toml(e)
+    }
+}
@@ -0,0 +1,260 @@
+use std::{fmt::Write as _, fs, io, path::Path};
+
+use crate::ExtensionCrate;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Os {
+    Linux,
+    Windows,
+    MacOs,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Arch {
+    X86_64,
+    Arm64,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Platform {
+    pub os: Os,
+    pub arch: Arch,
+}
+
+impl Platform {
+    pub const ALL: [Platform; 6] = [
+        Platform::new(Os::Linux, Arch::X86_64),
+        Platform::new(Os::Linux, Arch::Arm64),
+        Platform::new(Os::Windows, Arch::X86_64),
+        Platform::new(Os::Windows, Arch::Arm64),
+        Platform::new(Os::MacOs, Arch::X86_64),
+        Platform::new(Os::MacOs, Arch::Arm64),
+    ];
+
+    pub const fn new(os: Os, arch: Arch) -> Self {
+        Self { os, arch }
+    }
+
+    /// The Rust target triple to build for this platform.
+    pub fn target_triple(self) -> &'static str {
+        match (self.os, self.arch) {
+            (Os::Linux, Arch::X86_64) => "x86_64-unknown-linux-gnu",
+            (Os::Linux, Arch::Arm64) => "aarch64-unknown-linux-gnu",
+            (Os::Windows, Arch::X86_64) => "x86_64-pc-windows-msvc",
+            (Os::Windows, Arch::Arm64) => "aarch64-pc-windows-msvc",
+            (Os::MacOs, Arch::X86_64) => "x86_64-apple-darwin",
+            (Os::MacOs, Arch::Arm64) => "aarch64-apple-darwin",
+        }
+    }
+
+    /// Godot's feature tags for the OS and architecture.
+    fn feature_tags(self) -> (&'static str, &'static str) {
+        let os = match self.os {
+            Os::Linux => "linux",
+            Os::Windows => "windows",
+            Os::MacOs => "macos",
+        };
+        let arch = match self.arch {
+            Arch::X86_64 => "x86_64",
+            Arch::Arm64 => "arm64",
+        };
+        (os, arch)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Profile {
+    Debug,
+    Release,
+}
+
+impl Profile {
+    /// The name of the profile's directory in the target directory, and its Godot feature tag.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Debug => "debug",
+            Self::Release => "release",
+        }
+    }
+}
+
+/// Where the libraries of the different platforms are found below the library directory.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TargetDirLayout {
+    /// `<profile>/<file>`, as produced by `cargo build` for the host platform. All platforms are
+    /// listed, so that the project loads wherever the library was built for the host.
+    Flat,
+    /// `<target triple>/<profile>/<file>`, as produced by `cargo build --target`.
+    PerTarget,
+}
+
+/// The contents of a `.gdextension` file.
+#[derive(Clone, Debug)]
+pub struct Manifest {
+    library: ExtensionCrate,
+    library_dir: String,
+    layout: TargetDirLayout,
+    platforms: Vec<Platform>,
+    profiles: Vec<Profile>,
+}
+
+impl Manifest {
+    /// Creates a manifest listing the libraries of all platforms and profiles, in the target
+    /// directory next to the Godot project, with the layout of `cargo build --target`.
+    pub fn new(library: ExtensionCrate) -> Self {
+        Self {
+            library,
+            library_dir: "res://../target".to_string(),
+            layout: TargetDirLayout::PerTarget,
+            platforms: Platform::ALL.to_vec(),
+            profiles: vec![Profile::Debug, Profile::Release],
+        }
+    }
+
+    /// Sets the directory the library paths start with, usually a `res://` path.
+    pub fn library_dir(mut self, library_dir: impl Into<String>) -> Self {
+        self.library_dir = library_dir.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn layout(mut self, layout: TargetDirLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    pub fn platforms(mut self, platforms: impl IntoIterator<Item = Platform>) -> Self {
+        self.platforms = platforms.into_iter().collect();
+        self
+    }
+
+    pub fn profiles(mut self, profiles: impl IntoIterator<Item = Profile>) -> Self {
+        self.profiles = profiles.into_iter().collect();
+        self
+    }
+
+    /// Returns the path Godot loads the library from on `platform` for `profile`.
+    pub fn library_path(&self, platform: Platform, profile: Profile) -> String {
+        let file = self.library.library_file_name(platform.os);
+        match self.layout {
+            TargetDirLayout::Flat => format!("{}/{}/{}", self.library_dir, profile.name(), file),
+            TargetDirLayout::PerTarget => format!(
+                "{}/{}/{}/{}",
+                self.library_dir,
+                platform.target_triple(),
+                profile.name(),
+                file
+            ),
+        }
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "[configuration]").unwrap();
+        writeln!(out, "entry_symbol = \"{}\"", self.library.entry_symbol).unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "[libraries]").unwrap();
+
+        for &platform in &self.platforms {
+            let (os, arch) = platform.feature_tags();
+            for &profile in &self.profiles {
+                writeln!(
+                    out,
+                    "{}.{}.{} = \"{}\"",
+                    os,
+                    profile.name(),
+                    arch,
+                    self.library_path(platform, profile)
+                )
+                .unwrap();
+            }
+        }
+        out
+    }
+
+    /// Writes the manifest to `path`, unless it's already up to date.
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let contents = self.render();
+        if fs::read_to_string(path).is_ok_and(|existing| existing == contents) {
+            return Ok(());
+        }
+        fs::write(path, contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn library() -> ExtensionCrate {
+        ExtensionCrate {
+            root: PathBuf::from("native"),
+            library_name: "native".to_string(),
+            entry_symbol: "native_init".to_string(),
+        }
+    }
+
+    #[test]
+    fn renders_per_target_layout() {
+        let manifest = Manifest::new(library())
+            .library_dir("res://bin/")
+            .platforms([
+                Platform::new(Os::Linux, Arch::X86_64),
+                Platform::new(Os::Windows, Arch::X86_64),
+                Platform::new(Os::MacOs, Arch::Arm64),
+            ]);
+        assert_eq!(
+            manifest.render(),
+            "[configuration]\n\
+             entry_symbol = \"native_init\"\n\
+             \n\
+             [libraries]\n\
+             linux.debug.x86_64 = \"res://bin/x86_64-unknown-linux-gnu/debug/libnative.so\"\n\
+             linux.release.x86_64 = \"res://bin/x86_64-unknown-linux-gnu/release/libnative.so\"\n\
+             windows.debug.x86_64 = \"res://bin/x86_64-pc-windows-msvc/debug/native.dll\"\n\
+             windows.release.x86_64 = \"res://bin/x86_64-pc-windows-msvc/release/native.dll\"\n\
+             macos.debug.arm64 = \"res://bin/aarch64-apple-darwin/debug/libnative.dylib\"\n\
+             macos.release.arm64 = \"res://bin/aarch64-apple-darwin/release/libnative.dylib\"\n"
+        );
+    }
+
+    #[test]
+    fn renders_selected_profiles() {
+        let manifest = Manifest::new(library())
+            .platforms([Platform::new(Os::Linux, Arch::Arm64)])
+            .profiles([Profile::Release]);
+        assert_eq!(
+            manifest.render(),
+            "[configuration]\n\
+             entry_symbol = \"native_init\"\n\
+             \n\
+             [libraries]\n\
+             linux.release.arm64 = \"res://../target/aarch64-unknown-linux-gnu/release/libnative.so\"\n"
+        );
+    }
+
+    #[test]
+    fn renders_flat_layout_for_every_platform() {
+        let manifest = Manifest::new(library())
+            .layout(TargetDirLayout::Flat)
+            .platforms([
+                Platform::new(Os::Linux, Arch::X86_64),
+                Platform::new(Os::Windows, Arch::X86_64),
+                Platform::new(Os::MacOs, Arch::Arm64),
+            ]);
+        assert_eq!(
+            manifest.render(),
+            "[configuration]\n\
+             entry_symbol = \"native_init\"\n\
+             \n\
+             [libraries]\n\
+             linux.debug.x86_64 = \"res://../target/debug/libnative.so\"\n\
+             linux.release.x86_64 = \"res://../target/release/libnative.so\"\n\
+             windows.debug.x86_64 = \"res://../target/debug/native.dll\"\n\
+             windows.release.x86_64 = \"res://../target/release/native.dll\"\n\
+             macos.debug.arm64 = \"res://../target/debug/libnative.dylib\"\n\
+             macos.release.arm64 = \"res://../target/release/libnative.dylib\"\n"
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use std::{fs, path::Path};
+
+use crate::Error;
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.in");
+const LIB_RS: &str = include_str!("../templates/lib.rs.in");
+
+/// Creates a new extension crate in `dir`, with a `gdext_init!` entry point and an example class.
+///
+/// The crate is named after the directory, and depends on the gdext crates found in
+/// `gdext_path`.
+pub fn scaffold(dir: &Path, gdext_path: &Path) -> Result<(), Error> {
+    let name = dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or(Error::MissingLibraryName)?;
+    let entry_symbol = format!("{}_init", name.replace('-', "_"));
+    let gdext_path = gdext_path.to_string_lossy().replace('\\', "/");
+
+    let render = |template: &str| {
+        template
+            .replace("{{name}}", name)
+            .replace("{{entry_symbol}}", &entry_symbol)
+            .replace("{{gdext_path}}", &gdext_path)
+    };
+
+    let files = [
+        (dir.join("Cargo.toml"), render(CARGO_TOML)),
+        (dir.join("src").join("lib.rs"), render(LIB_RS)),
+    ];
+
+    if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+        return Err(Error::AlreadyExists(path.clone()));
+    }
+
+    for (path, contents) in files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)?;
+    }
+    Ok(())
+}
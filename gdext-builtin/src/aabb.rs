@@ -0,0 +1,287 @@
+use gdext_sys::real;
+
+use crate::{vector3::Vector3, PtrCallArg};
+
+/// An axis-aligned bounding box, given by its minimum corner `position` and its `size`.
+///
+/// Like in Godot, most methods assume a non-negative `size`, see [`AABB::abs`].
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[repr(C)]
+pub struct AABB {
+    pub position: Vector3,
+    pub size: Vector3,
+}
+
+impl AABB {
+    pub fn new(position: Vector3, size: Vector3) -> Self {
+        Self { position, size }
+    }
+
+    pub fn end(&self) -> Vector3 {
+        self.position + self.size
+    }
+
+    pub fn center(&self) -> Vector3 {
+        self.position + self.size * 0.5
+    }
+
+    pub fn volume(&self) -> real {
+        self.size.x * self.size.y * self.size.z
+    }
+
+    pub fn has_no_volume(&self) -> bool {
+        self.size.x <= 0.0 || self.size.y <= 0.0 || self.size.z <= 0.0
+    }
+
+    /// Returns an equivalent box with a non-negative size.
+    pub fn abs(&self) -> Self {
+        Self::new(self.position.min(self.end()), self.size.abs())
+    }
+
+    /// Returns `true` if `point` is inside the box or on its surface.
+    pub fn has_point(&self, point: Vector3) -> bool {
+        let end = self.end();
+        point.x >= self.position.x
+            && point.y >= self.position.y
+            && point.z >= self.position.z
+            && point.x <= end.x
+            && point.y <= end.y
+            && point.z <= end.z
+    }
+
+    /// Returns `true` if the boxes overlap. Boxes that only touch are not overlapping.
+    pub fn intersects(&self, other: &AABB) -> bool {
+        let (end, other_end) = (self.end(), other.end());
+        self.position.x < other_end.x
+            && end.x > other.position.x
+            && self.position.y < other_end.y
+            && end.y > other.position.y
+            && self.position.z < other_end.z
+            && end.z > other.position.z
+    }
+
+    /// Returns `true` if `other` is completely inside this box.
+    pub fn encloses(&self, other: &AABB) -> bool {
+        let (end, other_end) = (self.end(), other.end());
+        other.position.x >= self.position.x
+            && other.position.y >= self.position.y
+            && other.position.z >= self.position.z
+            && other_end.x <= end.x
+            && other_end.y <= end.y
+            && other_end.z <= end.z
+    }
+
+    /// Returns the overlapping volume of both boxes, or `None` if they don't intersect.
+    pub fn intersection(&self, other: &AABB) -> Option<AABB> {
+        if !self.intersects(other) {
+            return None;
+        }
+        let position = self.position.max(other.position);
+        let end = self.end().min(other.end());
+        Some(Self::new(position, end - position))
+    }
+
+    /// Returns the smallest box enclosing both boxes.
+    pub fn merge(&self, other: &AABB) -> AABB {
+        let position = self.position.min(other.position);
+        let end = self.end().max(other.end());
+        Self::new(position, end - position)
+    }
+
+    /// Returns a copy of the box expanded to include `point`.
+    pub fn expand(&self, point: Vector3) -> AABB {
+        let position = self.position.min(point);
+        let end = self.end().max(point);
+        Self::new(position, end - position)
+    }
+
+    /// Returns a copy of the box grown by `by` on all sides.
+    pub fn grow(&self, by: real) -> AABB {
+        Self::new(
+            self.position - Vector3::splat(by),
+            self.size + Vector3::splat(by * 2.0),
+        )
+    }
+
+    /// Returns the point where the ray starting at `from` and going along `dir` enters the box,
+    /// or `None` if the ray misses it. If `from` is inside the box, `from` itself is returned.
+    pub fn intersects_ray(&self, from: Vector3, dir: Vector3) -> Option<Vector3> {
+        let end = self.end();
+        let mut near = real::MIN;
+        let mut far = real::MAX;
+
+        for i in 0..3 {
+            if dir[i] == 0.0 {
+                if from[i] < self.position[i] || from[i] > end[i] {
+                    return None;
+                }
+            } else {
+                let mut t1 = (self.position[i] - from[i]) / dir[i];
+                let mut t2 = (end[i] - from[i]) / dir[i];
+                if t1 > t2 {
+                    std::mem::swap(&mut t1, &mut t2);
+                }
+                near = near.max(t1);
+                far = far.min(t2);
+                if near > far || far < 0.0 {
+                    return None;
+                }
+            }
+        }
+
+        Some(from + dir * near.max(0.0))
+    }
+
+    /// Returns the point where the segment from `from` to `to` enters the box, or `None` if the
+    /// segment misses it.
+    pub fn intersects_segment(&self, from: Vector3, to: Vector3) -> Option<Vector3> {
+        self.intersects_ray(from, to - from)
+            .filter(|point| (*point - from).length_squared() <= (to - from).length_squared())
+    }
+}
+
+impl PtrCallArg for AABB {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        *(*arg as *const AABB)
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        *(arg as *mut AABB) = self;
+    }
+}
+
+const _: () = {
+    use std::mem::{align_of, offset_of, size_of};
+
+    assert!(size_of::<AABB>() == 6 * size_of::<real>());
+    assert!(align_of::<AABB>() == align_of::<real>());
+    assert!(offset_of!(AABB, position) == 0);
+    assert!(offset_of!(AABB, size) == 3 * size_of::<real>());
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_box() -> AABB {
+        AABB::new(Vector3::ZERO, Vector3::ONE)
+    }
+
+    #[test]
+    fn touching_boxes_dont_intersect() {
+        let a = unit_box();
+        let touching = AABB::new(Vector3::new(1.0, 0.0, 0.0), Vector3::ONE);
+        assert!(!a.intersects(&touching));
+        assert_eq!(a.intersection(&touching), None);
+
+        let overlapping = AABB::new(Vector3::splat(0.5), Vector3::ONE);
+        assert!(a.intersects(&overlapping));
+        assert_eq!(
+            a.intersection(&overlapping),
+            Some(AABB::new(Vector3::splat(0.5), Vector3::splat(0.5)))
+        );
+    }
+
+    #[test]
+    fn includes_the_surface() {
+        let a = unit_box();
+        assert!(a.has_point(Vector3::ONE));
+        assert!(a.has_point(Vector3::new(0.0, 0.5, 1.0)));
+        assert!(!a.has_point(Vector3::new(0.5, 1.001, 0.5)));
+    }
+
+    #[test]
+    fn ray_enters_through_the_nearest_face() {
+        let a = unit_box();
+        assert_eq!(
+            a.intersects_ray(Vector3::new(0.5, 0.5, -2.0), Vector3::Z),
+            Some(Vector3::new(0.5, 0.5, 0.0))
+        );
+        assert_eq!(
+            a.intersects_ray(Vector3::new(-1.0, -1.0, -1.0), Vector3::ONE),
+            Some(Vector3::ZERO)
+        );
+    }
+
+    #[test]
+    fn ray_misses() {
+        let a = unit_box();
+        // Pointing away.
+        assert_eq!(
+            a.intersects_ray(Vector3::new(0.5, 0.5, -2.0), -Vector3::Z),
+            None
+        );
+        // Passing by.
+        assert_eq!(
+            a.intersects_ray(Vector3::new(2.0, 0.5, -2.0), Vector3::new(0.1, 0.0, 1.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn parallel_ray_hits_only_within_the_slab() {
+        let a = unit_box();
+        // Parallel to the x and y axes, inside their slabs, and along the faces.
+        assert_eq!(
+            a.intersects_ray(Vector3::new(0.5, 0.5, -2.0), Vector3::Z),
+            Some(Vector3::new(0.5, 0.5, 0.0))
+        );
+        assert_eq!(
+            a.intersects_ray(Vector3::new(0.0, 1.0, -2.0), Vector3::Z),
+            Some(Vector3::new(0.0, 1.0, 0.0))
+        );
+        // Parallel, outside the x slab.
+        assert_eq!(
+            a.intersects_ray(Vector3::new(1.5, 0.5, -2.0), Vector3::Z),
+            None
+        );
+    }
+
+    #[test]
+    fn ray_from_inside_returns_its_start() {
+        let from = Vector3::splat(0.5);
+        assert_eq!(unit_box().intersects_ray(from, Vector3::X), Some(from));
+    }
+
+    #[test]
+    fn segment_must_reach_the_box() {
+        let a = unit_box();
+        let from = Vector3::new(0.5, 0.5, -2.0);
+        assert_eq!(
+            a.intersects_segment(from, Vector3::new(0.5, 0.5, 3.0)),
+            Some(Vector3::new(0.5, 0.5, 0.0))
+        );
+        // Ending exactly on the surface.
+        assert_eq!(
+            a.intersects_segment(from, Vector3::new(0.5, 0.5, 0.0)),
+            Some(Vector3::new(0.5, 0.5, 0.0))
+        );
+        // Ending short of it.
+        assert_eq!(
+            a.intersects_segment(from, Vector3::new(0.5, 0.5, -0.5)),
+            None
+        );
+        // Parallel to a face, outside the box.
+        assert_eq!(
+            a.intersects_segment(Vector3::new(2.0, 0.5, -2.0), Vector3::new(2.0, 0.5, 2.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn merges_expands_and_grows() {
+        let a = unit_box();
+        let b = AABB::new(Vector3::new(2.0, -1.0, 0.0), Vector3::ONE);
+        assert_eq!(
+            a.merge(&b),
+            AABB::new(Vector3::new(0.0, -1.0, 0.0), Vector3::new(3.0, 2.0, 1.0))
+        );
+        assert_eq!(
+            a.expand(Vector3::new(0.5, 2.0, 0.5)),
+            AABB::new(Vector3::ZERO, Vector3::new(1.0, 2.0, 1.0))
+        );
+        assert_eq!(a.grow(1.0), AABB::new(-Vector3::ONE, Vector3::splat(3.0)));
+        assert_eq!(AABB::new(Vector3::ONE, -Vector3::ONE).abs(), unit_box());
+    }
+}
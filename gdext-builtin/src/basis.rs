@@ -0,0 +1,235 @@
+use std::ops::Mul;
+
+use gdext_sys::real;
+
+use crate::{
+    quaternion::{GlamQuat, Quaternion},
+    vector3::Vector3,
+    PtrCallArg,
+};
+
+#[cfg(not(feature = "real_is_double"))]
+pub(crate) type GlamMat3 = glam::f32::Mat3;
+#[cfg(feature = "real_is_double")]
+pub(crate) type GlamMat3 = glam::f64::DMat3;
+
+/// A 3x3 matrix representing a rotation and scale in 3D space.
+///
+/// The memory layout matches Godot's `Basis`, which stores the matrix as three *row* vectors.
+/// The basis vectors `x`, `y` and `z` Godot refers to are the columns of the matrix, see
+/// [`Basis::from_cols`] and [`Basis::col`].
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub struct Basis {
+    pub rows: [Vector3; 3],
+}
+
+impl Basis {
+    pub const IDENTITY: Self = Self {
+        rows: [Vector3::X, Vector3::Y, Vector3::Z],
+    };
+
+    pub fn from_rows(x: Vector3, y: Vector3, z: Vector3) -> Self {
+        Self { rows: [x, y, z] }
+    }
+
+    pub fn from_cols(x: Vector3, y: Vector3, z: Vector3) -> Self {
+        Self::from_rows(x, y, z).transposed()
+    }
+
+    /// Returns the column with the given index, i.e. one of the `x`, `y` or `z` basis vectors.
+    pub fn col(&self, index: usize) -> Vector3 {
+        Vector3::new(
+            self.rows[0][index],
+            self.rows[1][index],
+            self.rows[2][index],
+        )
+    }
+
+    pub fn set_col(&mut self, index: usize, col: Vector3) {
+        self.rows[0][index] = col.x;
+        self.rows[1][index] = col.y;
+        self.rows[2][index] = col.z;
+    }
+
+    pub fn transposed(&self) -> Self {
+        Self::from_rows(self.col(0), self.col(1), self.col(2))
+    }
+
+    pub fn determinant(&self) -> real {
+        GlamMat3::from(*self).determinant()
+    }
+
+    pub fn inverse(&self) -> Self {
+        GlamMat3::from(*self).inverse().into()
+    }
+
+    pub fn from_quaternion(quat: Quaternion) -> Self {
+        GlamMat3::from_quat(quat.into()).into()
+    }
+
+    pub fn to_quaternion(&self) -> Quaternion {
+        GlamQuat::from_mat3(&(*self).into()).into()
+    }
+}
+
+impl Default for Basis {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<GlamMat3> for Basis {
+    fn from(m: GlamMat3) -> Self {
+        Self::from_cols(m.x_axis, m.y_axis, m.z_axis)
+    }
+}
+
+impl From<Basis> for GlamMat3 {
+    fn from(b: Basis) -> Self {
+        GlamMat3::from_cols(b.col(0), b.col(1), b.col(2))
+    }
+}
+
+impl From<Quaternion> for Basis {
+    fn from(quat: Quaternion) -> Self {
+        Self::from_quaternion(quat)
+    }
+}
+
+impl Mul<Vector3> for Basis {
+    type Output = Vector3;
+
+    fn mul(self, v: Vector3) -> Vector3 {
+        Vector3::new(
+            self.rows[0].dot(v),
+            self.rows[1].dot(v),
+            self.rows[2].dot(v),
+        )
+    }
+}
+
+impl Mul for Basis {
+    type Output = Basis;
+
+    fn mul(self, other: Basis) -> Basis {
+        (GlamMat3::from(self) * GlamMat3::from(other)).into()
+    }
+}
+
+impl PtrCallArg for Basis {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        *(*arg as *const Basis)
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        *(arg as *mut Basis) = self;
+    }
+}
+
+const _: () = {
+    use std::mem::{align_of, offset_of, size_of};
+
+    assert!(size_of::<Basis>() == 9 * size_of::<real>());
+    assert!(align_of::<Basis>() == align_of::<real>());
+    assert!(offset_of!(Basis, rows) == 0);
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAC_PI_2: real = std::f64::consts::FRAC_PI_2 as real;
+    const PI: real = std::f64::consts::PI as real;
+
+    fn assert_close(a: Basis, b: Basis) {
+        for (row_a, row_b) in a.rows.iter().zip(&b.rows) {
+            assert!(row_a.abs_diff_eq(*row_b, 1e-5), "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn stores_basis_vectors_as_columns() {
+        let basis = Basis::from_cols(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(4.0, 5.0, 6.0),
+            Vector3::new(7.0, 8.0, 9.0),
+        );
+        assert_eq!(basis.rows[0], Vector3::new(1.0, 4.0, 7.0));
+        assert_eq!(basis.col(1), Vector3::new(4.0, 5.0, 6.0));
+        assert_eq!(basis * Vector3::X, Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(basis.transposed().rows[0], Vector3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn converts_to_glam_and_back() {
+        let basis = Basis::from_rows(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(4.0, 5.0, 6.0),
+            Vector3::new(7.0, 8.0, 10.0),
+        );
+        let mat = GlamMat3::from(basis);
+        assert_eq!(mat.x_axis, basis.col(0));
+        assert_eq!(Basis::from(mat), basis);
+    }
+
+    #[test]
+    fn rotates_like_godot() {
+        // Basis(Vector3.UP, PI / 2) in Godot: x = (0, 0, -1), y = (0, 1, 0), z = (1, 0, 0).
+        let basis = Basis::from_quaternion(Quaternion::from_axis_angle(Vector3::Y, FRAC_PI_2));
+        assert_close(
+            basis,
+            Basis::from_cols(
+                Vector3::new(0.0, 0.0, -1.0),
+                Vector3::Y,
+                Vector3::new(1.0, 0.0, 0.0),
+            ),
+        );
+        assert!((basis * Vector3::X).abs_diff_eq(Vector3::new(0.0, 0.0, -1.0), 1e-5));
+    }
+
+    #[test]
+    fn composes_rotations() {
+        let quarter = Basis::from_quaternion(Quaternion::from_axis_angle(Vector3::Y, FRAC_PI_2));
+        let half = Basis::from_quaternion(Quaternion::from_axis_angle(Vector3::Y, PI));
+        assert_close(quarter * quarter, half);
+        assert!((quarter * quarter * Vector3::X).abs_diff_eq(-Vector3::X, 1e-5));
+    }
+
+    #[test]
+    fn applies_the_right_operand_first() {
+        let scale = Basis::from_cols(Vector3::X * 2.0, Vector3::Y, Vector3::Z);
+        let rotation = Basis::from_quaternion(Quaternion::from_axis_angle(Vector3::Z, FRAC_PI_2));
+        let v = Vector3::new(1.0, 1.0, 0.0);
+        assert!(((scale * rotation) * v).abs_diff_eq(scale * (rotation * v), 1e-5));
+        assert!(((scale * rotation) * v).abs_diff_eq(Vector3::new(-2.0, 1.0, 0.0), 1e-5));
+    }
+
+    #[test]
+    fn computes_determinant_and_inverse() {
+        let basis = Basis::from_cols(
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector3::new(1.0, 3.0, 0.0),
+            Vector3::new(0.0, 0.0, 4.0),
+        );
+        assert!((basis.determinant() - 24.0).abs() < 1e-5);
+        assert_close(basis * basis.inverse(), Basis::IDENTITY);
+        assert_close(basis.inverse() * basis, Basis::IDENTITY);
+    }
+
+    #[test]
+    fn round_trips_quaternions() {
+        let quat = Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), 0.7);
+        let back = Basis::from_quaternion(quat).to_quaternion();
+        // `q` and `-q` are the same rotation.
+        let sign = if back.w * quat.w < 0.0 { -1.0 } else { 1.0 };
+        for (a, b) in [
+            (back.x, quat.x),
+            (back.y, quat.y),
+            (back.z, quat.z),
+            (back.w, quat.w),
+        ] {
+            assert!((a * sign - b).abs() < 1e-5, "{back:?} != {quat:?}");
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::mem::MaybeUninit;
+
+use gdext_sys::{self as sys, interface_fn, lazy::Lazy};
+
+use crate::{
+    string_name::StringName,
+    variant::{CallError, Variant},
+    PtrCallArg,
+};
+
+/// A reference to a method on a specific object, Godot's equivalent of a bound method.
+#[repr(C, align(8))]
+pub struct Callable(MaybeUninit<[u8; 16]>);
+
+impl Callable {
+    fn uninit() -> Self {
+        Self(MaybeUninit::uninit())
+    }
+
+    #[doc(hidden)]
+    pub fn as_mut_ptr(&mut self) -> sys::GDNativeTypePtr {
+        self.0.as_mut_ptr() as *mut _
+    }
+    #[doc(hidden)]
+    pub fn as_ptr(&self) -> sys::GDNativeTypePtr {
+        self.0.as_ptr() as *mut _
+    }
+
+    /// Creates a null callable, which can't be called.
+    pub fn new() -> Self {
+        unsafe {
+            let mut c = Self::uninit();
+
+            static CONSTR: Lazy<
+                unsafe extern "C" fn(sys::GDNativeTypePtr, *const sys::GDNativeTypePtr),
+            > = Lazy::new(|| unsafe {
+                interface_fn!(variant_get_ptr_constructor)(
+                    sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_CALLABLE,
+                    0,
+                )
+                .unwrap()
+            });
+            CONSTR(c.as_mut_ptr(), std::ptr::null());
+            c
+        }
+    }
+
+    /// Creates a callable for the method `method` of `object`.
+    ///
+    /// # Safety
+    ///
+    /// `object` must point to a live engine object.
+    pub unsafe fn from_object_method(
+        object: sys::GDNativeObjectPtr,
+        method: &str,
+    ) -> Result<Self, CallError> {
+        let args = [
+            Variant::from_object_ptr(object),
+            Variant::from(StringName::from(method)),
+        ];
+        let args = args.iter().map(Variant::as_ptr).collect::<Vec<_>>();
+
+        let mut ret = Variant::nil();
+        let mut err = sys::GDNativeCallError {
+            error: sys::GDNativeCallErrorType_GDNATIVE_CALL_OK,
+            argument: 0,
+            expected: 0,
+        };
+        interface_fn!(variant_construct)(
+            sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_CALLABLE,
+            ret.as_mut_ptr(),
+            args.as_ptr(),
+            args.len() as _,
+            &mut err,
+        );
+
+        if err.error == sys::GDNativeCallErrorType_GDNATIVE_CALL_OK {
+            Ok(Callable::from(&ret))
+        } else {
+            Err(CallError(err))
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.variant_call("is_null", &[])
+            .map_or(true, |ret| bool::from(&ret))
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.variant_call("is_valid", &[])
+            .is_ok_and(|ret| bool::from(&ret))
+    }
+
+    pub fn method_name(&self) -> StringName {
+        self.variant_call("get_method", &[])
+            .map(|ret| StringName::from(&ret))
+            .unwrap_or_default()
+    }
+
+    pub fn object_id(&self) -> u64 {
+        self.variant_call("get_object_id", &[])
+            .map_or(0, |ret| u64::from(&ret))
+    }
+
+    /// Calls the method with the given arguments.
+    pub fn call(&self, args: &[Variant]) -> Result<Variant, CallError> {
+        self.variant_call("call", args)
+    }
+
+    /// Calls the method at the end of the current frame, once the engine is idle.
+    pub fn call_deferred(&self, args: &[Variant]) -> Result<(), CallError> {
+        self.variant_call("call_deferred", args).map(|_| ())
+    }
+
+    /// Returns a copy of this callable that appends `args` to the arguments of every call.
+    pub fn bind(&self, args: &[Variant]) -> Callable {
+        self.variant_call("bind", args)
+            .map(|ret| Callable::from(&ret))
+            .unwrap_or_default()
+    }
+
+    fn variant_call(&self, method: &str, args: &[Variant]) -> Result<Variant, CallError> {
+        Variant::from(self).call(method, args)
+    }
+}
+
+impl Default for Callable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for Callable {
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut c = Self::uninit();
+
+            static CONSTR: Lazy<
+                unsafe extern "C" fn(sys::GDNativeTypePtr, *const sys::GDNativeTypePtr),
+            > = Lazy::new(|| unsafe {
+                interface_fn!(variant_get_ptr_constructor)(
+                    sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_CALLABLE,
+                    1,
+                )
+                .unwrap()
+            });
+            let args = [self.as_ptr()];
+            CONSTR(c.as_mut_ptr(), args.as_ptr());
+            c
+        }
+    }
+}
+
+impl Drop for Callable {
+    fn drop(&mut self) {
+        unsafe {
+            static DESTR: Lazy<unsafe extern "C" fn(sys::GDNativeTypePtr)> = Lazy::new(|| unsafe {
+                interface_fn!(variant_get_ptr_destructor)(
+                    sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_CALLABLE,
+                )
+                .unwrap()
+            });
+            DESTR(self.as_mut_ptr());
+        }
+    }
+}
+
+impl PtrCallArg for Callable {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        Clone::clone(&*(*arg as *const Callable))
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        std::ptr::write(arg as *mut Callable, self);
+    }
+}
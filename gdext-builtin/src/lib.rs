@@ -1,23 +1,46 @@
 #![macro_use]
 
+pub mod aabb;
+pub mod basis;
+pub mod callable;
+pub mod logging;
+pub mod plane;
+pub mod projection;
+pub mod quaternion;
+pub mod rect2;
+pub mod rect2i;
+pub mod rid;
+pub mod signal;
+pub mod stats;
 pub mod string;
+pub mod string_name;
+pub mod transform2d;
+pub mod transform3d;
 pub mod variant;
 pub mod vector2;
+pub mod vector2i;
 pub mod vector3;
+pub mod vector3i;
+pub mod vector4;
+pub mod vector4i;
+pub mod version;
 
 pub mod macros;
 
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, fmt};
 
 pub use glam;
 
+#[doc(hidden)]
+pub use gdext_sys as sys;
+
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub enum InitLevel {
     Core,
     Servers,
     Scene,
-    Editor,
     Driver,
+    Editor,
 }
 
 impl InitLevel {
@@ -44,9 +67,22 @@ impl InitLevel {
     }
 }
 
+type InitCallback = Box<dyn FnOnce() + 'static>;
+
+struct InitEntry {
+    name: Option<&'static str>,
+    dependencies: Vec<&'static str>,
+    f: InitCallback,
+}
+
+/// The callbacks to run as Godot goes through the initialization levels.
+///
+/// Any number of callbacks can be registered per level. Init callbacks run in registration order,
+/// deinit callbacks in reverse registration order, unless dependencies between named callbacks
+/// call for a different order.
 pub struct InitOptions {
-    init_levels: BTreeMap<InitLevel, Box<dyn FnOnce() + 'static>>,
-    deinit_levels: BTreeMap<InitLevel, Box<dyn FnOnce() + 'static>>,
+    init_levels: BTreeMap<InitLevel, Vec<InitEntry>>,
+    deinit_levels: BTreeMap<InitLevel, Vec<InitEntry>>,
     lowest_level: InitLevel,
 }
 
@@ -60,84 +96,249 @@ impl InitOptions {
     }
 
     pub fn register_init_function(&mut self, level: InitLevel, f: impl FnOnce() + 'static) {
-        self.init_levels.insert(level, Box::new(f));
-        self.lowest_level = self.lowest_level.min(level);
+        self.push_init(level, None, &[], Box::new(f));
+    }
+
+    /// Registers an init callback under `name`, which runs after the init callbacks named in
+    /// `dependencies`.
+    ///
+    /// Dependencies have to be registered at the same or a lower level. They are checked by
+    /// [`validate`](Self::validate), as they may be registered after their dependents.
+    pub fn register_named_init_function(
+        &mut self,
+        name: &'static str,
+        level: InitLevel,
+        dependencies: &[&'static str],
+        f: impl FnOnce() + 'static,
+    ) -> Result<(), InitError> {
+        check_unique_name(&self.init_levels, name)?;
+        self.push_init(level, Some(name), dependencies, Box::new(f));
+        Ok(())
     }
 
     pub fn register_deinit_function(&mut self, level: InitLevel, f: impl FnOnce() + 'static) {
-        self.deinit_levels.insert(level, Box::new(f));
+        self.push_deinit(level, None, &[], Box::new(f));
+    }
+
+    /// Registers a deinit callback under `name`, which runs before the deinit callbacks named in
+    /// `dependencies`, i.e. things are torn down in the reverse order of how they were set up.
+    pub fn register_named_deinit_function(
+        &mut self,
+        name: &'static str,
+        level: InitLevel,
+        dependencies: &[&'static str],
+        f: impl FnOnce() + 'static,
+    ) -> Result<(), InitError> {
+        check_unique_name(&self.deinit_levels, name)?;
+        self.push_deinit(level, Some(name), dependencies, Box::new(f));
+        Ok(())
     }
 
     pub fn lowest_init_level(&self) -> InitLevel {
         self.lowest_level
     }
 
+    /// Checks that the dependencies of all named callbacks are registered, at the same or a
+    /// lower level, and don't depend on each other in a cycle.
+    pub fn validate(&self) -> Result<(), InitError> {
+        validate_levels(&self.init_levels)?;
+        validate_levels(&self.deinit_levels)
+    }
+
     pub fn run_init_function(&mut self, level: InitLevel) {
-        if let Some(f) = self.init_levels.remove(&level) {
-            f();
+        if let Some(entries) = self.init_levels.remove(&level) {
+            for f in into_execution_order(entries) {
+                f();
+            }
         }
     }
 
     pub fn run_deinit_function(&mut self, level: InitLevel) {
-        if let Some(f) = self.deinit_levels.remove(&level) {
-            f();
+        if let Some(entries) = self.deinit_levels.remove(&level) {
+            for f in into_execution_order(entries).into_iter().rev() {
+                f();
+            }
         }
     }
+
+    fn push_init(
+        &mut self,
+        level: InitLevel,
+        name: Option<&'static str>,
+        dependencies: &[&'static str],
+        f: InitCallback,
+    ) {
+        push_entry(&mut self.init_levels, level, name, dependencies, f);
+        self.lowest_level = self.lowest_level.min(level);
+    }
+
+    fn push_deinit(
+        &mut self,
+        level: InitLevel,
+        name: Option<&'static str>,
+        dependencies: &[&'static str],
+        f: InitCallback,
+    ) {
+        push_entry(&mut self.deinit_levels, level, name, dependencies, f);
+        self.lowest_level = self.lowest_level.min(level);
+    }
 }
 
-impl Default for InitOptions {
-    fn default() -> Self {
-        Self::new()
+fn push_entry(
+    levels: &mut BTreeMap<InitLevel, Vec<InitEntry>>,
+    level: InitLevel,
+    name: Option<&'static str>,
+    dependencies: &[&'static str],
+    f: InitCallback,
+) {
+    levels.entry(level).or_default().push(InitEntry {
+        name,
+        dependencies: dependencies.to_vec(),
+        f,
+    });
+}
+
+fn check_unique_name(
+    levels: &BTreeMap<InitLevel, Vec<InitEntry>>,
+    name: &'static str,
+) -> Result<(), InitError> {
+    let registered = levels
+        .values()
+        .flatten()
+        .any(|entry| entry.name == Some(name));
+    if registered {
+        return Err(InitError::DuplicateName(name));
     }
+    Ok(())
 }
 
-#[doc(hidden)]
-pub static mut INIT_OPTIONS: Option<InitOptions> = None;
+fn validate_levels(levels: &BTreeMap<InitLevel, Vec<InitEntry>>) -> Result<(), InitError> {
+    for (&level, entries) in levels {
+        for entry in entries {
+            let name = entry.name.unwrap_or("<unnamed>");
+            for &dependency in &entry.dependencies {
+                let dependency_level = levels
+                    .iter()
+                    .find(|(_, entries)| entries.iter().any(|e| e.name == Some(dependency)))
+                    .map(|(&level, _)| level);
 
-#[macro_export]
-macro_rules! gdext_init {
-    ($name:ident, $f:expr) => {
-        #[no_mangle]
-        unsafe extern "C" fn gdext_rust_test(
-            interface: *const gdext_sys::GDNativeInterface,
-            library: gdext_sys::GDNativeExtensionClassLibraryPtr,
-            init: *mut gdext_sys::GDNativeInitialization,
-        ) {
-            gdext_sys::set_interface(interface);
-            gdext_sys::set_library(library);
-
-            let mut init_options = $crate::InitOptions::new();
-
-            ($f)(&mut init_options);
-
-            *init = sys::GDNativeInitialization {
-                minimum_initialization_level: init_options.lowest_init_level().to_sys(),
-                userdata: std::ptr::null_mut(),
-                initialize: Some(initialise),
-                deinitialize: Some(deinitialise),
-            };
-
-            $crate::INIT_OPTIONS = Some(init_options);
+                match dependency_level {
+                    None => return Err(InitError::UnknownDependency { name, dependency }),
+                    Some(dependency_level) if dependency_level > level => {
+                        return Err(InitError::DependencyAtHigherLevel { name, dependency })
+                    }
+                    Some(_) => {}
+                }
+            }
         }
+        execution_order(entries).map_err(InitError::DependencyCycle)?;
+    }
+    Ok(())
+}
+
+/// Returns the indices of `entries` sorted so that every entry comes after its dependencies on
+/// the same level, keeping registration order otherwise. Dependencies on lower levels have
+/// already run by the time this level is reached.
+///
+/// Fails with the names of the entries left over if they depend on each other in a cycle.
+fn execution_order(entries: &[InitEntry]) -> Result<Vec<usize>, Vec<&'static str>> {
+    let mut order = Vec::with_capacity(entries.len());
+    let mut done = vec![false; entries.len()];
+
+    while order.len() < entries.len() {
+        let is_pending = |name: &'static str| {
+            entries
+                .iter()
+                .zip(&done)
+                .any(|(entry, &done)| !done && entry.name == Some(name))
+        };
+        let next = (0..entries.len())
+            .find(|&i| !done[i] && !entries[i].dependencies.iter().any(|&d| is_pending(d)));
 
-        unsafe extern "C" fn initialise(
-            _userdata: *mut std::ffi::c_void,
-            init_level: gdext_sys::GDNativeInitializationLevel,
-        ) {
-            let init_options = $crate::INIT_OPTIONS.as_mut().unwrap();
-            init_options.run_init_function(InitLevel::from_sys(init_level));
+        match next {
+            Some(i) => {
+                done[i] = true;
+                order.push(i);
+            }
+            None => {
+                return Err(entries
+                    .iter()
+                    .zip(&done)
+                    .filter(|(_, &done)| !done)
+                    .map(|(entry, _)| entry.name.unwrap_or("<unnamed>"))
+                    .collect())
+            }
         }
+    }
+    Ok(order)
+}
+
+fn into_execution_order(entries: Vec<InitEntry>) -> Vec<InitCallback> {
+    // A cycle has been reported by `validate` already, fall back to registration order.
+    let order = execution_order(&entries).unwrap_or_else(|_| (0..entries.len()).collect());
 
-        unsafe extern "C" fn deinitialise(
-            _userdata: *mut std::ffi::c_void,
-            init_level: gdext_sys::GDNativeInitializationLevel,
-        ) {
-            let init_options = $crate::INIT_OPTIONS.as_mut().unwrap();
-            init_options.run_deinit_function(InitLevel::from_sys(init_level));
+    let mut callbacks = entries
+        .into_iter()
+        .map(|entry| Some(entry.f))
+        .collect::<Vec<_>>();
+    order
+        .into_iter()
+        .filter_map(|i| callbacks[i].take())
+        .collect()
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum InitError {
+    /// Another callback of the same kind has already been registered under this name.
+    DuplicateName(&'static str),
+    /// The callback `name` depends on a callback that has not been registered.
+    UnknownDependency {
+        name: &'static str,
+        dependency: &'static str,
+    },
+    /// The callback `name` depends on a callback that only runs at a later level.
+    DependencyAtHigherLevel {
+        name: &'static str,
+        dependency: &'static str,
+    },
+    /// The named callbacks depend on each other in a cycle.
+    DependencyCycle(Vec<&'static str>),
+}
+
+impl fmt::Display for InitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateName(name) => {
+                write!(f, "init callback `{name}` is registered more than once")
+            }
+            Self::UnknownDependency { name, dependency } => write!(
+                f,
+                "init callback `{name}` depends on `{dependency}`, which is not registered"
+            ),
+            Self::DependencyAtHigherLevel { name, dependency } => write!(
+                f,
+                "init callback `{name}` depends on `{dependency}`, which is registered at a higher level"
+            ),
+            Self::DependencyCycle(names) => write!(
+                f,
+                "init callbacks depend on each other in a cycle: {}",
+                names.join(", ")
+            ),
         }
-    };
+    }
+}
+
+impl std::error::Error for InitError {}
+
+impl Default for InitOptions {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
+#[doc(hidden)]
+pub static mut INIT_OPTIONS: Option<InitOptions> = None;
+
 pub trait PtrCallArg {
     /// Read an argument value from a ptrcall argument.
     ///
@@ -190,3 +391,184 @@ impl PtrCallArg for () {
         // do nothing
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+
+    type Log = Rc<RefCell<Vec<&'static str>>>;
+
+    fn logger(log: &Log, name: &'static str) -> impl FnOnce() + 'static {
+        let log = log.clone();
+        move || log.borrow_mut().push(name)
+    }
+
+    #[test]
+    fn init_runs_in_registration_order() {
+        let log = Log::default();
+        let mut options = InitOptions::new();
+        options.register_init_function(InitLevel::Scene, logger(&log, "a"));
+        options.register_init_function(InitLevel::Scene, logger(&log, "b"));
+        options.register_init_function(InitLevel::Core, logger(&log, "core"));
+        options.register_init_function(InitLevel::Scene, logger(&log, "c"));
+
+        options.run_init_function(InitLevel::Scene);
+        assert_eq!(*log.borrow(), ["a", "b", "c"]);
+        assert_eq!(options.lowest_init_level(), InitLevel::Core);
+    }
+
+    #[test]
+    fn deinit_runs_in_reverse_registration_order() {
+        let log = Log::default();
+        let mut options = InitOptions::new();
+        options.register_deinit_function(InitLevel::Scene, logger(&log, "a"));
+        options.register_deinit_function(InitLevel::Scene, logger(&log, "b"));
+        options.register_deinit_function(InitLevel::Scene, logger(&log, "c"));
+
+        options.run_deinit_function(InitLevel::Scene);
+        assert_eq!(*log.borrow(), ["c", "b", "a"]);
+    }
+
+    #[test]
+    fn dependencies_reorder_callbacks() {
+        let log = Log::default();
+        let mut options = InitOptions::new();
+        options
+            .register_named_init_function("a", InitLevel::Scene, &["b"], logger(&log, "a"))
+            .unwrap();
+        options.register_init_function(InitLevel::Scene, logger(&log, "unnamed"));
+        options
+            .register_named_init_function("b", InitLevel::Scene, &[], logger(&log, "b"))
+            .unwrap();
+        assert_eq!(options.validate(), Ok(()));
+
+        options.run_init_function(InitLevel::Scene);
+        assert_eq!(*log.borrow(), ["unnamed", "b", "a"]);
+    }
+
+    #[test]
+    fn deinit_dependencies_run_after_their_dependents() {
+        let log = Log::default();
+        let mut options = InitOptions::new();
+        options
+            .register_named_deinit_function("b", InitLevel::Scene, &[], logger(&log, "b"))
+            .unwrap();
+        options
+            .register_named_deinit_function("a", InitLevel::Scene, &["b"], logger(&log, "a"))
+            .unwrap();
+        options
+            .register_named_deinit_function("c", InitLevel::Scene, &[], logger(&log, "c"))
+            .unwrap();
+        assert_eq!(options.validate(), Ok(()));
+
+        options.run_deinit_function(InitLevel::Scene);
+        assert_eq!(*log.borrow(), ["c", "a", "b"]);
+    }
+
+    #[test]
+    fn duplicate_names_are_rejected() {
+        let mut options = InitOptions::new();
+        options
+            .register_named_init_function("a", InitLevel::Core, &[], || {})
+            .unwrap();
+        assert_eq!(
+            options.register_named_init_function("a", InitLevel::Scene, &[], || {}),
+            Err(InitError::DuplicateName("a"))
+        );
+
+        // Init and deinit callbacks have separate names.
+        assert_eq!(
+            options.register_named_deinit_function("a", InitLevel::Scene, &[], || {}),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn unknown_dependencies_are_rejected() {
+        let mut options = InitOptions::new();
+        options
+            .register_named_init_function("a", InitLevel::Scene, &["missing"], || {})
+            .unwrap();
+        assert_eq!(
+            options.validate(),
+            Err(InitError::UnknownDependency {
+                name: "a",
+                dependency: "missing",
+            })
+        );
+    }
+
+    #[test]
+    fn dependencies_on_higher_levels_are_rejected() {
+        let mut options = InitOptions::new();
+        options
+            .register_named_init_function("core", InitLevel::Core, &["scene"], || {})
+            .unwrap();
+        options
+            .register_named_init_function("scene", InitLevel::Scene, &[], || {})
+            .unwrap();
+        assert_eq!(
+            options.validate(),
+            Err(InitError::DependencyAtHigherLevel {
+                name: "core",
+                dependency: "scene",
+            })
+        );
+    }
+
+    #[test]
+    fn dependencies_on_lower_levels_are_accepted() {
+        let log = Log::default();
+        let mut options = InitOptions::new();
+        options
+            .register_named_init_function(
+                "scene",
+                InitLevel::Scene,
+                &["core"],
+                logger(&log, "scene"),
+            )
+            .unwrap();
+        options
+            .register_named_init_function("core", InitLevel::Core, &[], logger(&log, "core"))
+            .unwrap();
+        assert_eq!(options.validate(), Ok(()));
+
+        options.run_init_function(InitLevel::Core);
+        options.run_init_function(InitLevel::Scene);
+        assert_eq!(*log.borrow(), ["core", "scene"]);
+    }
+
+    #[test]
+    fn cycles_are_rejected() {
+        let log = Log::default();
+        let mut options = InitOptions::new();
+        options.register_init_function(InitLevel::Scene, logger(&log, "unnamed"));
+        options
+            .register_named_init_function("a", InitLevel::Scene, &["c"], logger(&log, "a"))
+            .unwrap();
+        options
+            .register_named_init_function("b", InitLevel::Scene, &["a"], logger(&log, "b"))
+            .unwrap();
+        options
+            .register_named_init_function("c", InitLevel::Scene, &["b"], logger(&log, "c"))
+            .unwrap();
+        assert_eq!(
+            options.validate(),
+            Err(InitError::DependencyCycle(vec!["a", "b", "c"]))
+        );
+
+        // Running anyway falls back to registration order.
+        options.run_init_function(InitLevel::Scene);
+        assert_eq!(*log.borrow(), ["unnamed", "a", "b", "c"]);
+    }
+
+    #[test]
+    fn levels_are_ordered_like_the_engine_initializes_them() {
+        assert!(InitLevel::Core < InitLevel::Servers);
+        assert!(InitLevel::Servers < InitLevel::Scene);
+        assert!(InitLevel::Scene < InitLevel::Driver);
+        assert!(InitLevel::Driver < InitLevel::Editor);
+    }
+}
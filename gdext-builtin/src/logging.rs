@@ -0,0 +1,517 @@
+//! Routes messages to Godot's output: the `gdext_print*!` macros, and with the `log` and `tracing`
+//! features, [`GodotLogger`] and [`GodotSubscriber`] for libraries using those crates.
+
+use std::{cell::RefCell, fmt, io::Write as _};
+
+use gdext_sys::{self as sys, interface_fn, lazy::Lazy};
+
+use crate::{string::GodotString, variant::Variant};
+
+#[cfg(feature = "log")]
+pub use self::log_backend::GodotLogger;
+#[cfg(feature = "tracing")]
+pub use self::tracing_backend::GodotSubscriber;
+
+/// Where a message shows up in Godot.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Severity {
+    /// Printed as is, like GDScript's `print`.
+    Print,
+    Warning,
+    Error,
+    ScriptError,
+}
+
+/// Hash of the `print` utility function in `extension_api.json`.
+const PRINT_HASH: sys::GDNativeInt = 2648703342;
+
+thread_local! {
+    /// Reused by [`print`] for the nul-terminated message, function and file.
+    static BUFFER: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Prints `message` to Godot's output, reporting it as coming from `function` (usually a module
+/// path) in `file` at `line` for warnings and errors.
+///
+/// Outside of Godot, e.g. in unit tests, messages go to the standard output and error instead.
+#[doc(hidden)]
+pub fn print(severity: Severity, message: fmt::Arguments, function: &str, file: &str, line: u32) {
+    if sys::try_get_interface().is_none() {
+        print_std(severity, message, function, file, line);
+        return;
+    }
+
+    BUFFER.with(|buffer| match buffer.try_borrow_mut() {
+        Ok(mut buffer) => print_with_buffer(&mut buffer, severity, message, function, file, line),
+        // Printing while formatting a message that is being printed.
+        Err(_) => print_with_buffer(&mut Vec::new(), severity, message, function, file, line),
+    });
+}
+
+fn print_with_buffer(
+    buffer: &mut Vec<u8>,
+    severity: Severity,
+    message: fmt::Arguments,
+    function: &str,
+    file: &str,
+    line: u32,
+) {
+    buffer.clear();
+    write!(buffer, "{}\0", message).unwrap();
+    let function_start = buffer.len();
+    write!(buffer, "{}\0", function).unwrap();
+    let file_start = buffer.len();
+    write!(buffer, "{}\0", file).unwrap();
+
+    let message = buffer.as_ptr() as *const _;
+    let function = buffer[function_start..].as_ptr() as *const _;
+    let file = buffer[file_start..].as_ptr() as *const _;
+    let line = line as _;
+
+    unsafe {
+        match severity {
+            Severity::Print => print_plain(&buffer[..function_start - 1]),
+            Severity::Warning => interface_fn!(print_warning)(message, function, file, line),
+            Severity::Error => interface_fn!(print_error)(message, function, file, line),
+            Severity::ScriptError => {
+                interface_fn!(print_script_error)(message, function, file, line)
+            }
+        }
+    }
+}
+
+/// Prints like Godot does to the terminal.
+fn print_std(severity: Severity, message: fmt::Arguments, function: &str, file: &str, line: u32) {
+    let text = format_std(severity, message, function, file, line);
+    match severity {
+        Severity::Print => println!("{}", text),
+        _ => eprintln!("{}", text),
+    }
+}
+
+fn format_std(
+    severity: Severity,
+    message: fmt::Arguments,
+    function: &str,
+    file: &str,
+    line: u32,
+) -> String {
+    let prefix = match severity {
+        Severity::Print => return message.to_string(),
+        Severity::Warning => "WARNING",
+        Severity::Error => "ERROR",
+        Severity::ScriptError => "SCRIPT ERROR",
+    };
+    format!(
+        "{}: {}\n   at: {} ({}:{})",
+        prefix, message, function, file, line
+    )
+}
+
+fn print_plain(message: &[u8]) {
+    static PRINT: Lazy<sys::GDNativePtrUtilityFunction> = Lazy::new(|| unsafe {
+        interface_fn!(variant_get_ptr_utility_function)(c"print".as_ptr(), PRINT_HASH)
+    });
+
+    let message = String::from_utf8_lossy(message);
+    match *PRINT {
+        Some(print) => {
+            let arg = Variant::from(GodotString::from(&message));
+            let args = [arg.as_ptr() as sys::GDNativeTypePtr];
+            let mut ret = Variant::nil();
+            unsafe { print(ret.as_mut_ptr() as *mut _, args.as_ptr(), args.len() as _) };
+        }
+        None => println!("{}", message),
+    }
+}
+
+/// A level of detail of a log message, from the most to the least important.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+impl Level {
+    fn severity(self) -> Severity {
+        match self {
+            Level::Error => Severity::Error,
+            Level::Warn => Severity::Warning,
+            Level::Info | Level::Debug | Level::Trace => Severity::Print,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+}
+
+/// Which messages are printed, by level and target (usually the module path).
+///
+/// A target's level applies to the target and everything below it (`my_crate::net` also covers
+/// `my_crate::net::http`); the most specific one wins. A level of `None` turns messages off.
+#[derive(Clone, Debug)]
+pub struct Filter {
+    default: Option<Level>,
+    targets: Vec<(String, Option<Level>)>,
+}
+
+impl Filter {
+    /// Prints messages up to `default` from all targets.
+    pub fn new(default: Option<Level>) -> Self {
+        Self {
+            default,
+            targets: Vec::new(),
+        }
+    }
+
+    pub fn target(mut self, target: impl Into<String>, level: Option<Level>) -> Self {
+        self.targets.push((target.into(), level));
+        self
+    }
+
+    pub fn level_for(&self, target: &str) -> Option<Level> {
+        self.targets
+            .iter()
+            .filter(|(prefix, _)| {
+                target
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default, |&(_, level)| level)
+    }
+
+    pub fn enabled(&self, level: Level, target: &str) -> bool {
+        self.level_for(target).is_some_and(|max| level <= max)
+    }
+
+    /// The most detailed level printed for any target.
+    pub fn max_level(&self) -> Option<Level> {
+        self.targets
+            .iter()
+            .map(|&(_, level)| level)
+            .chain([self.default])
+            .max()
+            .flatten()
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self::new(Some(Level::Info))
+    }
+}
+
+/// Prints a message from a log record or tracing event.
+#[cfg(any(feature = "log", feature = "tracing"))]
+fn print_record(
+    level: Level,
+    target: &str,
+    message: fmt::Arguments,
+    module_path: Option<&str>,
+    file: Option<&str>,
+    line: Option<u32>,
+) {
+    let function = module_path.unwrap_or(target);
+    let file = file.unwrap_or("<unknown>");
+    let line = line.unwrap_or(0);
+
+    match level.severity() {
+        // Godot already marks warnings and errors, and shows where they come from.
+        Severity::Print => print(
+            Severity::Print,
+            format_args!("[{} {}] {}", level.name(), target, message),
+            function,
+            file,
+            line,
+        ),
+        severity => print(severity, message, function, file, line),
+    }
+}
+
+#[cfg(feature = "log")]
+mod log_backend {
+    use super::{print_record, Filter, Level};
+
+    /// A [`log::Log`] printing to Godot's output: errors with `print_error`, warnings with
+    /// `print_warning`, and everything else as plain messages.
+    pub struct GodotLogger {
+        filter: Filter,
+    }
+
+    impl GodotLogger {
+        pub fn new(filter: Filter) -> Self {
+            Self { filter }
+        }
+
+        /// Installs the logger as the `log` crate's logger.
+        pub fn init(self) -> Result<(), log::SetLoggerError> {
+            log::set_max_level(match self.filter.max_level() {
+                Some(level) => to_log_level(level).to_level_filter(),
+                None => log::LevelFilter::Off,
+            });
+            log::set_boxed_logger(Box::new(self))
+        }
+    }
+
+    fn to_log_level(level: Level) -> log::Level {
+        match level {
+            Level::Error => log::Level::Error,
+            Level::Warn => log::Level::Warn,
+            Level::Info => log::Level::Info,
+            Level::Debug => log::Level::Debug,
+            Level::Trace => log::Level::Trace,
+        }
+    }
+
+    fn from_log_level(level: log::Level) -> Level {
+        match level {
+            log::Level::Error => Level::Error,
+            log::Level::Warn => Level::Warn,
+            log::Level::Info => Level::Info,
+            log::Level::Debug => Level::Debug,
+            log::Level::Trace => Level::Trace,
+        }
+    }
+
+    impl log::Log for GodotLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            self.filter
+                .enabled(from_log_level(metadata.level()), metadata.target())
+        }
+
+        fn log(&self, record: &log::Record) {
+            if !self.enabled(record.metadata()) {
+                return;
+            }
+            print_record(
+                from_log_level(record.level()),
+                record.target(),
+                *record.args(),
+                record.module_path(),
+                record.file(),
+                record.line(),
+            );
+        }
+
+        fn flush(&self) {}
+    }
+}
+
+#[cfg(feature = "tracing")]
+mod tracing_backend {
+    use std::{
+        fmt::{self, Write as _},
+        sync::atomic::{AtomicU64, Ordering},
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    use super::{print_record, Filter, Level};
+
+    /// A [`tracing::Subscriber`] printing events to Godot's output: errors with `print_error`,
+    /// warnings with `print_warning`, and everything else as plain messages.
+    ///
+    /// Spans are only used for filtering; their fields aren't printed.
+    pub struct GodotSubscriber {
+        filter: Filter,
+        next_span_id: AtomicU64,
+    }
+
+    impl GodotSubscriber {
+        pub fn new(filter: Filter) -> Self {
+            Self {
+                filter,
+                next_span_id: AtomicU64::new(1),
+            }
+        }
+
+        /// Installs the subscriber as the global default.
+        pub fn init(self) -> Result<(), tracing::subscriber::SetGlobalDefaultError> {
+            tracing::subscriber::set_global_default(self)
+        }
+    }
+
+    fn from_tracing_level(level: &tracing::Level) -> Level {
+        match *level {
+            tracing::Level::ERROR => Level::Error,
+            tracing::Level::WARN => Level::Warn,
+            tracing::Level::INFO => Level::Info,
+            tracing::Level::DEBUG => Level::Debug,
+            tracing::Level::TRACE => Level::Trace,
+        }
+    }
+
+    /// Collects the `message` field, followed by the other fields as `name=value`.
+    #[derive(Default)]
+    struct MessageVisitor {
+        message: String,
+        fields: String,
+    }
+
+    impl Visit for MessageVisitor {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            if field.name() == "message" {
+                write!(self.message, "{:?}", value).unwrap();
+            } else {
+                write!(self.fields, " {}={:?}", field.name(), value).unwrap();
+            }
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "message" {
+                self.message.push_str(value);
+            } else {
+                write!(self.fields, " {}={:?}", field.name(), value).unwrap();
+            }
+        }
+    }
+
+    impl Subscriber for GodotSubscriber {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            self.filter
+                .enabled(from_tracing_level(metadata.level()), metadata.target())
+        }
+
+        fn max_level_hint(&self) -> Option<tracing::level_filters::LevelFilter> {
+            Some(match self.filter.max_level() {
+                Some(Level::Error) => tracing::level_filters::LevelFilter::ERROR,
+                Some(Level::Warn) => tracing::level_filters::LevelFilter::WARN,
+                Some(Level::Info) => tracing::level_filters::LevelFilter::INFO,
+                Some(Level::Debug) => tracing::level_filters::LevelFilter::DEBUG,
+                Some(Level::Trace) => tracing::level_filters::LevelFilter::TRACE,
+                None => tracing::level_filters::LevelFilter::OFF,
+            })
+        }
+
+        fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(self.next_span_id.fetch_add(1, Ordering::Relaxed))
+        }
+
+        fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let metadata = event.metadata();
+            let mut visitor = MessageVisitor::default();
+            event.record(&mut visitor);
+
+            print_record(
+                from_tracing_level(metadata.level()),
+                metadata.target(),
+                format_args!("{}{}", visitor.message, visitor.fields),
+                metadata.module_path(),
+                metadata.file(),
+                metadata.line(),
+            );
+        }
+
+        fn enter(&self, _span: &span::Id) {}
+
+        fn exit(&self, _span: &span::Id) {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_level_applies_without_targets() {
+        let filter = Filter::new(Some(Level::Info));
+        assert_eq!(filter.level_for("my_crate::net"), Some(Level::Info));
+        assert!(filter.enabled(Level::Warn, "my_crate"));
+        assert!(filter.enabled(Level::Info, "my_crate"));
+        assert!(!filter.enabled(Level::Debug, "my_crate"));
+    }
+
+    #[test]
+    fn targets_cover_their_submodules() {
+        let filter = Filter::new(Some(Level::Info)).target("my_crate::net", Some(Level::Trace));
+        assert_eq!(filter.level_for("my_crate::net"), Some(Level::Trace));
+        assert_eq!(filter.level_for("my_crate::net::http"), Some(Level::Trace));
+        // Only at a `::` boundary.
+        assert_eq!(filter.level_for("my_crate::network"), Some(Level::Info));
+        assert_eq!(filter.level_for("my_crate"), Some(Level::Info));
+    }
+
+    #[test]
+    fn longest_target_wins() {
+        let filter = Filter::new(None)
+            .target("my_crate::net", Some(Level::Trace))
+            .target("my_crate", Some(Level::Warn))
+            .target("my_crate::net::http", Some(Level::Error));
+        assert_eq!(filter.level_for("my_crate::db"), Some(Level::Warn));
+        assert_eq!(filter.level_for("my_crate::net::tcp"), Some(Level::Trace));
+        assert_eq!(
+            filter.level_for("my_crate::net::http::client"),
+            Some(Level::Error)
+        );
+        assert_eq!(filter.level_for("other_crate"), None);
+    }
+
+    #[test]
+    fn none_turns_targets_off() {
+        let filter = Filter::new(Some(Level::Trace)).target("noisy", None);
+        assert_eq!(filter.level_for("noisy::inner"), None);
+        assert!(!filter.enabled(Level::Error, "noisy::inner"));
+        assert!(filter.enabled(Level::Trace, "quiet"));
+    }
+
+    #[test]
+    fn max_level_is_the_most_detailed() {
+        assert_eq!(Filter::default().max_level(), Some(Level::Info));
+        assert_eq!(
+            Filter::new(Some(Level::Warn))
+                .target("a", Some(Level::Debug))
+                .target("b", None)
+                .max_level(),
+            Some(Level::Debug)
+        );
+        assert_eq!(Filter::new(None).target("a", None).max_level(), None);
+    }
+
+    #[test]
+    fn prints_without_godot() {
+        // Falls back to the standard output and error rather than calling into the engine.
+        print(Severity::Print, format_args!("message"), "f", "file.rs", 1);
+        print(Severity::Error, format_args!("message"), "f", "file.rs", 1);
+    }
+
+    #[test]
+    fn formats_like_godot() {
+        assert_eq!(
+            format_std(Severity::Print, format_args!("hello {}", 1), "f", "a.rs", 2),
+            "hello 1"
+        );
+        assert_eq!(
+            format_std(
+                Severity::Warning,
+                format_args!("careful"),
+                "my_crate::f",
+                "a.rs",
+                2
+            ),
+            "WARNING: careful\n   at: my_crate::f (a.rs:2)"
+        );
+        assert_eq!(
+            format_std(Severity::ScriptError, format_args!("oops"), "f", "a.rs", 3),
+            "SCRIPT ERROR: oops\n   at: f (a.rs:3)"
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use gdext_sys::real;
+
+use crate::{vector3::Vector3, PtrCallArg};
+
+/// Same tolerance as Godot's `CMP_EPSILON`.
+const CMP_EPSILON: real = 0.00001;
+
+/// A plane in Hessian normal form: all points `p` with `normal.dot(p) == d`.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[repr(C)]
+pub struct Plane {
+    pub normal: Vector3,
+    pub d: real,
+}
+
+impl Plane {
+    pub fn new(normal: Vector3, d: real) -> Self {
+        Self { normal, d }
+    }
+
+    pub fn from_point_normal(point: Vector3, normal: Vector3) -> Self {
+        Self::new(normal, normal.dot(point))
+    }
+
+    /// Creates the plane going through the three points, facing the side from which they appear
+    /// in clockwise order.
+    pub fn from_points(a: Vector3, b: Vector3, c: Vector3) -> Self {
+        let normal = (a - c).cross(a - b).normalize();
+        Self::from_point_normal(a, normal)
+    }
+
+    pub fn normalized(&self) -> Self {
+        let len = self.normal.length();
+        if len == 0.0 {
+            return Self::default();
+        }
+        Self::new(self.normal / len, self.d / len)
+    }
+
+    /// Returns the point on the plane closest to the origin.
+    pub fn center(&self) -> Vector3 {
+        self.normal * self.d
+    }
+
+    /// Returns the signed distance from the plane to `point`; positive if the point is above it.
+    pub fn distance_to(&self, point: Vector3) -> real {
+        self.normal.dot(point) - self.d
+    }
+
+    pub fn is_point_over(&self, point: Vector3) -> bool {
+        self.distance_to(point) > 0.0
+    }
+
+    pub fn has_point(&self, point: Vector3, tolerance: real) -> bool {
+        self.distance_to(point).abs() <= tolerance
+    }
+
+    /// Returns the orthogonal projection of `point` onto the plane.
+    pub fn project(&self, point: Vector3) -> Vector3 {
+        point - self.normal * self.distance_to(point)
+    }
+
+    /// Returns the point where the ray starting at `from` and going along `dir` hits the plane,
+    /// or `None` if the ray is parallel to the plane or points away from it.
+    pub fn intersects_ray(&self, from: Vector3, dir: Vector3) -> Option<Vector3> {
+        let den = self.normal.dot(dir);
+        if den.abs() <= CMP_EPSILON {
+            return None;
+        }
+        let dist = (self.normal.dot(from) - self.d) / den;
+        if dist > CMP_EPSILON {
+            return None;
+        }
+        Some(from - dir * dist)
+    }
+
+    /// Returns the point where the segment from `from` to `to` crosses the plane, if it does.
+    pub fn intersects_segment(&self, from: Vector3, to: Vector3) -> Option<Vector3> {
+        let segment = from - to;
+        let den = self.normal.dot(segment);
+        if den.abs() <= CMP_EPSILON {
+            return None;
+        }
+        let dist = (self.normal.dot(from) - self.d) / den;
+        if !(-CMP_EPSILON..=1.0 + CMP_EPSILON).contains(&dist) {
+            return None;
+        }
+        Some(from - segment * dist)
+    }
+}
+
+impl PtrCallArg for Plane {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        *(*arg as *const Plane)
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        *(arg as *mut Plane) = self;
+    }
+}
+
+const _: () = {
+    use std::mem::{align_of, offset_of, size_of};
+
+    assert!(size_of::<Plane>() == 4 * size_of::<real>());
+    assert!(align_of::<Plane>() == align_of::<real>());
+    assert!(offset_of!(Plane, normal) == 0);
+    assert!(offset_of!(Plane, d) == 3 * size_of::<real>());
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The plane `y = 1`, facing up.
+    fn plane() -> Plane {
+        Plane::new(Vector3::Y, 1.0)
+    }
+
+    #[test]
+    fn measures_signed_distances() {
+        let p = plane();
+        assert_eq!(p.distance_to(Vector3::new(3.0, 4.0, 0.0)), 3.0);
+        assert_eq!(p.distance_to(Vector3::ZERO), -1.0);
+        assert!(p.is_point_over(Vector3::new(0.0, 1.5, 0.0)));
+        assert!(!p.is_point_over(Vector3::new(0.0, 1.0, 0.0)));
+        assert!(p.has_point(Vector3::new(5.0, 1.0, -5.0), CMP_EPSILON));
+        assert_eq!(
+            p.project(Vector3::new(2.0, 7.0, 3.0)),
+            Vector3::new(2.0, 1.0, 3.0)
+        );
+        assert_eq!(p.center(), Vector3::Y);
+    }
+
+    #[test]
+    fn builds_from_points_and_normalizes() {
+        // Plane(Vector3(0, 1, 0), Vector3(1, 1, 0), Vector3(0, 1, 1)) in Godot faces up.
+        let p = Plane::from_points(
+            Vector3::Y,
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(0.0, 1.0, 1.0),
+        );
+        assert_eq!(p, Plane::new(Vector3::Y, 1.0));
+        assert!(p.has_point(Vector3::new(7.0, 1.0, -3.0), CMP_EPSILON));
+
+        assert_eq!(
+            Plane::new(Vector3::Y * 2.0, 4.0).normalized(),
+            Plane::new(Vector3::Y, 2.0)
+        );
+        assert_eq!(
+            Plane::new(Vector3::ZERO, 4.0).normalized(),
+            Plane::default()
+        );
+    }
+
+    #[test]
+    fn segment_crossing_the_plane_intersects() {
+        assert_eq!(
+            plane().intersects_segment(Vector3::new(1.0, 3.0, 0.0), Vector3::new(1.0, -1.0, 0.0)),
+            Some(Vector3::new(1.0, 1.0, 0.0))
+        );
+        assert_eq!(
+            plane().intersects_segment(Vector3::new(0.0, -1.0, 0.0), Vector3::new(2.0, 3.0, 0.0)),
+            Some(Vector3::new(1.0, 1.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn segment_ending_on_the_plane_intersects() {
+        assert_eq!(
+            plane().intersects_segment(Vector3::new(0.0, 2.0, 0.0), Vector3::Y),
+            Some(Vector3::Y)
+        );
+        assert_eq!(
+            plane().intersects_segment(Vector3::Y, Vector3::new(0.0, 2.0, 0.0)),
+            Some(Vector3::Y)
+        );
+    }
+
+    #[test]
+    fn segment_short_of_the_plane_misses() {
+        assert_eq!(
+            plane().intersects_segment(Vector3::new(0.0, 3.0, 0.0), Vector3::new(0.0, 1.5, 0.0)),
+            None
+        );
+        assert_eq!(
+            plane().intersects_segment(Vector3::new(0.0, -3.0, 0.0), Vector3::ZERO),
+            None
+        );
+    }
+
+    #[test]
+    fn parallel_segment_misses() {
+        assert_eq!(
+            plane().intersects_segment(Vector3::new(0.0, 2.0, 0.0), Vector3::new(5.0, 2.0, 0.0)),
+            None
+        );
+        // Even when lying in the plane, like in Godot.
+        assert_eq!(
+            plane().intersects_segment(Vector3::Y, Vector3::new(5.0, 1.0, 0.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn ray_hits_only_in_front() {
+        let from = Vector3::new(2.0, 3.0, 0.0);
+        assert_eq!(
+            plane().intersects_ray(from, -Vector3::Y),
+            Some(Vector3::new(2.0, 1.0, 0.0))
+        );
+        assert_eq!(plane().intersects_ray(from, Vector3::Y), None);
+        assert_eq!(plane().intersects_ray(from, Vector3::X), None);
+    }
+}
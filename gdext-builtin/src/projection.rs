@@ -0,0 +1,144 @@
+use std::ops::Mul;
+
+use gdext_sys::real;
+
+use crate::{
+    transform3d::Transform3D,
+    vector3::Vector3,
+    vector4::{GlamVec4, Vector4},
+    PtrCallArg,
+};
+
+#[cfg(not(feature = "real_is_double"))]
+type GlamMat4 = glam::f32::Mat4;
+#[cfg(feature = "real_is_double")]
+type GlamMat4 = glam::f64::DMat4;
+
+/// A 4x4 matrix used for 3D projective transformations, stored as four column vectors like
+/// Godot's `Projection`.
+///
+/// The `gdnative_interface.h` these bindings are generated from predates the `PROJECTION`
+/// variant type, so this only supports ptrcalls and has no
+/// [`Variant`](crate::variant::Variant) conversions yet.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub struct Projection {
+    pub columns: [Vector4; 4],
+}
+
+impl Projection {
+    pub const IDENTITY: Self = Self {
+        columns: [
+            Vector4::new(1.0, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, 1.0, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, 1.0, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        ],
+    };
+
+    pub fn from_cols(x: Vector4, y: Vector4, z: Vector4, w: Vector4) -> Self {
+        Self {
+            columns: [x, y, z, w],
+        }
+    }
+
+    /// Creates a right-handed perspective projection with OpenGL's `[-1, 1]` depth range, which
+    /// is what Godot's `Projection.create_perspective` produces.
+    pub fn perspective(fov_y_radians: real, aspect: real, z_near: real, z_far: real) -> Self {
+        GlamMat4::perspective_rh_gl(fov_y_radians, aspect, z_near, z_far).into()
+    }
+
+    pub fn orthographic(
+        left: real,
+        right: real,
+        bottom: real,
+        top: real,
+        z_near: real,
+        z_far: real,
+    ) -> Self {
+        GlamMat4::orthographic_rh_gl(left, right, bottom, top, z_near, z_far).into()
+    }
+
+    pub fn determinant(&self) -> real {
+        GlamMat4::from(*self).determinant()
+    }
+
+    pub fn inverse(&self) -> Self {
+        GlamMat4::from(*self).inverse().into()
+    }
+
+    /// Transforms `point` and performs the perspective divide.
+    pub fn xform(&self, point: Vector3) -> Vector3 {
+        GlamMat4::from(*self).project_point3(point)
+    }
+}
+
+impl Default for Projection {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<GlamMat4> for Projection {
+    fn from(m: GlamMat4) -> Self {
+        Self::from_cols(
+            m.x_axis.into(),
+            m.y_axis.into(),
+            m.z_axis.into(),
+            m.w_axis.into(),
+        )
+    }
+}
+
+impl From<Projection> for GlamMat4 {
+    fn from(p: Projection) -> Self {
+        let [x, y, z, w] = p.columns.map(GlamVec4::from);
+        GlamMat4::from_cols(x, y, z, w)
+    }
+}
+
+impl From<Transform3D> for Projection {
+    fn from(t: Transform3D) -> Self {
+        let b = t.basis;
+        Self::from_cols(
+            b.col(0).extend(0.0).into(),
+            b.col(1).extend(0.0).into(),
+            b.col(2).extend(0.0).into(),
+            t.origin.extend(1.0).into(),
+        )
+    }
+}
+
+impl Mul for Projection {
+    type Output = Projection;
+
+    fn mul(self, other: Projection) -> Projection {
+        (GlamMat4::from(self) * GlamMat4::from(other)).into()
+    }
+}
+
+impl Mul<Vector4> for Projection {
+    type Output = Vector4;
+
+    fn mul(self, v: Vector4) -> Vector4 {
+        (GlamMat4::from(self) * GlamVec4::from(v)).into()
+    }
+}
+
+impl PtrCallArg for Projection {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        *(*arg as *const Projection)
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        *(arg as *mut Projection) = self;
+    }
+}
+
+const _: () = {
+    use std::mem::{align_of, offset_of, size_of};
+
+    assert!(size_of::<Projection>() == 16 * size_of::<real>());
+    assert!(align_of::<Projection>() == align_of::<real>());
+    assert!(offset_of!(Projection, columns) == 0);
+};
@@ -0,0 +1,164 @@
+use std::ops::Mul;
+
+use gdext_sys::real;
+
+use crate::{vector3::Vector3, PtrCallArg};
+
+#[cfg(not(feature = "real_is_double"))]
+pub(crate) type GlamQuat = glam::f32::Quat;
+#[cfg(feature = "real_is_double")]
+pub(crate) type GlamQuat = glam::f64::DQuat;
+
+/// A unit quaternion representing a 3D rotation.
+///
+/// glam's `Quat` is SIMD-aligned and can't be passed to Godot directly, so this type mirrors
+/// Godot's plain `x, y, z, w` layout and converts to and from glam for the actual math.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub struct Quaternion {
+    pub x: real,
+    pub y: real,
+    pub z: real,
+    pub w: real,
+}
+
+impl Quaternion {
+    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: real, y: real, z: real, w: real) -> Self {
+        Self { x, y, z, w }
+    }
+
+    pub fn from_axis_angle(axis: Vector3, angle: real) -> Self {
+        GlamQuat::from_axis_angle(axis, angle).into()
+    }
+
+    pub fn length(self) -> real {
+        GlamQuat::from(self).length()
+    }
+
+    pub fn normalized(self) -> Self {
+        GlamQuat::from(self).normalize().into()
+    }
+
+    pub fn inverse(self) -> Self {
+        GlamQuat::from(self).inverse().into()
+    }
+
+    pub fn slerp(self, to: Self, weight: real) -> Self {
+        GlamQuat::from(self).slerp(to.into(), weight).into()
+    }
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<GlamQuat> for Quaternion {
+    fn from(q: GlamQuat) -> Self {
+        Self::new(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl From<Quaternion> for GlamQuat {
+    fn from(q: Quaternion) -> Self {
+        GlamQuat::from_xyzw(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl Mul<Vector3> for Quaternion {
+    type Output = Vector3;
+
+    fn mul(self, v: Vector3) -> Vector3 {
+        GlamQuat::from(self).mul_vec3(v)
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, other: Quaternion) -> Quaternion {
+        (GlamQuat::from(self) * GlamQuat::from(other)).into()
+    }
+}
+
+impl PtrCallArg for Quaternion {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        *(*arg as *const Quaternion)
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        *(arg as *mut Quaternion) = self;
+    }
+}
+
+const _: () = {
+    use std::mem::{align_of, offset_of, size_of};
+
+    assert!(size_of::<Quaternion>() == 4 * size_of::<real>());
+    assert!(align_of::<Quaternion>() == align_of::<real>());
+    assert!(offset_of!(Quaternion, x) == 0);
+    assert!(offset_of!(Quaternion, y) == size_of::<real>());
+    assert!(offset_of!(Quaternion, z) == 2 * size_of::<real>());
+    assert!(offset_of!(Quaternion, w) == 3 * size_of::<real>());
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAC_PI_2: real = std::f64::consts::FRAC_PI_2 as real;
+    const FRAC_PI_4: real = std::f64::consts::FRAC_PI_4 as real;
+
+    fn assert_close(a: Quaternion, b: Quaternion) {
+        assert!(
+            GlamQuat::from(a).abs_diff_eq(GlamQuat::from(b), 1e-5),
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn builds_from_axis_angle_like_godot() {
+        // Quaternion(Vector3.UP, PI / 2) in Godot.
+        let sqrt_half = (0.5 as real).sqrt();
+        assert_close(
+            Quaternion::from_axis_angle(Vector3::Y, FRAC_PI_2),
+            Quaternion::new(0.0, sqrt_half, 0.0, sqrt_half),
+        );
+    }
+
+    #[test]
+    fn rotates_vectors() {
+        let quat = Quaternion::from_axis_angle(Vector3::Z, FRAC_PI_2);
+        assert!((quat * Vector3::X).abs_diff_eq(Vector3::Y, 1e-5));
+        assert!((quat.inverse() * Vector3::Y).abs_diff_eq(Vector3::X, 1e-5));
+    }
+
+    #[test]
+    fn composes_rotations() {
+        let quarter = Quaternion::from_axis_angle(Vector3::Z, FRAC_PI_2);
+        let eighth = Quaternion::from_axis_angle(Vector3::Z, FRAC_PI_4);
+        assert_close(eighth * eighth, quarter);
+        assert_close(quarter * quarter.inverse(), Quaternion::IDENTITY);
+    }
+
+    #[test]
+    fn slerps_along_the_arc() {
+        let to = Quaternion::from_axis_angle(Vector3::Y, FRAC_PI_2);
+        assert_close(Quaternion::IDENTITY.slerp(to, 0.0), Quaternion::IDENTITY);
+        assert_close(Quaternion::IDENTITY.slerp(to, 1.0), to);
+        // Quaternion().slerp(Quaternion(Vector3.UP, PI / 2), 0.5) in Godot: (0, 0.382683, 0, 0.92388).
+        let halfway = Quaternion::IDENTITY.slerp(to, 0.5);
+        assert_close(halfway, Quaternion::new(0.0, 0.382_683_4, 0.0, 0.923_879_5));
+        assert!((halfway.length() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn normalizes() {
+        let quat = Quaternion::new(0.0, 3.0, 0.0, 4.0);
+        assert!((quat.length() - 5.0).abs() < 1e-5);
+        assert_close(quat.normalized(), Quaternion::new(0.0, 0.6, 0.0, 0.8));
+    }
+}
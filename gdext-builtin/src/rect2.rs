@@ -0,0 +1,224 @@
+use gdext_sys::real;
+
+use crate::{vector2::Vector2, PtrCallArg};
+
+/// An axis-aligned 2D rectangle, given by its top-left `position` and its `size`.
+///
+/// Like in Godot, most methods assume a non-negative `size`, see [`Rect2::abs`].
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[repr(C)]
+pub struct Rect2 {
+    pub position: Vector2,
+    pub size: Vector2,
+}
+
+impl Rect2 {
+    pub fn new(position: Vector2, size: Vector2) -> Self {
+        Self { position, size }
+    }
+
+    pub fn from_corners(a: Vector2, b: Vector2) -> Self {
+        Self::new(a, b - a).abs()
+    }
+
+    pub fn end(&self) -> Vector2 {
+        self.position + self.size
+    }
+
+    pub fn center(&self) -> Vector2 {
+        self.position + self.size * 0.5
+    }
+
+    pub fn area(&self) -> real {
+        self.size.x * self.size.y
+    }
+
+    pub fn has_no_area(&self) -> bool {
+        self.size.x <= 0.0 || self.size.y <= 0.0
+    }
+
+    /// Returns an equivalent rect with a non-negative size.
+    pub fn abs(&self) -> Self {
+        Self::new(self.position.min(self.end()), self.size.abs())
+    }
+
+    /// Returns `true` if `point` is inside the rect. Points on the right and bottom edges are
+    /// considered outside.
+    pub fn has_point(&self, point: Vector2) -> bool {
+        point.x >= self.position.x
+            && point.y >= self.position.y
+            && point.x < self.end().x
+            && point.y < self.end().y
+    }
+
+    /// Returns `true` if the rects overlap. With `include_borders`, rects that only touch are
+    /// also considered to be overlapping.
+    pub fn intersects(&self, other: &Rect2, include_borders: bool) -> bool {
+        let (end, other_end) = (self.end(), other.end());
+        if include_borders {
+            self.position.x <= other_end.x
+                && end.x >= other.position.x
+                && self.position.y <= other_end.y
+                && end.y >= other.position.y
+        } else {
+            self.position.x < other_end.x
+                && end.x > other.position.x
+                && self.position.y < other_end.y
+                && end.y > other.position.y
+        }
+    }
+
+    /// Returns `true` if `other` is completely inside this rect.
+    pub fn encloses(&self, other: &Rect2) -> bool {
+        other.position.x >= self.position.x
+            && other.position.y >= self.position.y
+            && other.end().x <= self.end().x
+            && other.end().y <= self.end().y
+    }
+
+    /// Returns the overlapping area of both rects, or `None` if they don't intersect.
+    pub fn intersection(&self, other: &Rect2) -> Option<Rect2> {
+        if !self.intersects(other, false) {
+            return None;
+        }
+        let position = self.position.max(other.position);
+        let end = self.end().min(other.end());
+        Some(Self::new(position, end - position))
+    }
+
+    /// Returns the smallest rect enclosing both rects.
+    pub fn merge(&self, other: &Rect2) -> Rect2 {
+        let position = self.position.min(other.position);
+        let end = self.end().max(other.end());
+        Self::new(position, end - position)
+    }
+
+    /// Returns a copy of the rect expanded to include `point`.
+    pub fn expand(&self, point: Vector2) -> Rect2 {
+        let position = self.position.min(point);
+        let end = self.end().max(point);
+        Self::new(position, end - position)
+    }
+
+    /// Returns a copy of the rect grown by `by` on all sides.
+    pub fn grow(&self, by: real) -> Rect2 {
+        self.grow_individual(by, by, by, by)
+    }
+
+    pub fn grow_individual(&self, left: real, top: real, right: real, bottom: real) -> Rect2 {
+        Self::new(
+            self.position - Vector2::new(left, top),
+            self.size + Vector2::new(left + right, top + bottom),
+        )
+    }
+}
+
+impl PtrCallArg for Rect2 {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        *(*arg as *const Rect2)
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        *(arg as *mut Rect2) = self;
+    }
+}
+
+const _: () = {
+    use std::mem::{align_of, offset_of, size_of};
+
+    assert!(size_of::<Rect2>() == 4 * size_of::<real>());
+    assert!(align_of::<Rect2>() == align_of::<real>());
+    assert!(offset_of!(Rect2, position) == 0);
+    assert!(offset_of!(Rect2, size) == 2 * size_of::<real>());
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: real, y: real, width: real, height: real) -> Rect2 {
+        Rect2::new(Vector2::new(x, y), Vector2::new(width, height))
+    }
+
+    #[test]
+    fn overlapping_rects_intersect() {
+        let a = rect(0.0, 0.0, 2.0, 2.0);
+        let b = rect(1.0, 1.0, 2.0, 2.0);
+        assert!(a.intersects(&b, false));
+        assert!(a.intersects(&b, true));
+        assert_eq!(a.intersection(&b), Some(rect(1.0, 1.0, 1.0, 1.0)));
+    }
+
+    #[test]
+    fn touching_rects_only_intersect_including_borders() {
+        let a = rect(0.0, 0.0, 1.0, 1.0);
+        for touching in [
+            rect(1.0, 0.0, 1.0, 1.0),
+            rect(0.0, 1.0, 1.0, 1.0),
+            rect(1.0, 1.0, 1.0, 1.0),
+        ] {
+            assert!(!a.intersects(&touching, false));
+            assert!(!touching.intersects(&a, false));
+            assert!(a.intersects(&touching, true));
+            assert!(touching.intersects(&a, true));
+            assert_eq!(a.intersection(&touching), None);
+        }
+    }
+
+    #[test]
+    fn separate_rects_dont_intersect() {
+        let a = rect(0.0, 0.0, 1.0, 1.0);
+        let b = rect(1.5, 0.0, 1.0, 1.0);
+        assert!(!a.intersects(&b, false));
+        assert!(!a.intersects(&b, true));
+    }
+
+    #[test]
+    fn excludes_right_and_bottom_edges() {
+        let r = rect(0.0, 0.0, 2.0, 2.0);
+        assert!(r.has_point(Vector2::ZERO));
+        assert!(r.has_point(Vector2::new(1.999, 1.999)));
+        assert!(!r.has_point(Vector2::new(2.0, 1.0)));
+        assert!(!r.has_point(Vector2::new(1.0, 2.0)));
+        assert!(!r.has_point(Vector2::new(-0.001, 1.0)));
+    }
+
+    #[test]
+    fn encloses_rects_sharing_edges() {
+        let r = rect(0.0, 0.0, 2.0, 2.0);
+        assert!(r.encloses(&r));
+        assert!(r.encloses(&rect(1.0, 1.0, 1.0, 1.0)));
+        assert!(!r.encloses(&rect(1.0, 1.0, 1.5, 1.0)));
+    }
+
+    #[test]
+    fn merges_and_expands() {
+        let a = rect(0.0, 0.0, 1.0, 1.0);
+        assert_eq!(
+            a.merge(&rect(-2.0, 3.0, 1.0, 1.0)),
+            rect(-2.0, 0.0, 3.0, 4.0)
+        );
+        assert_eq!(a.expand(Vector2::new(3.0, -1.0)), rect(0.0, -1.0, 3.0, 2.0));
+        assert_eq!(a.expand(Vector2::new(0.5, 0.5)), a);
+    }
+
+    #[test]
+    fn normalizes_negative_sizes() {
+        assert_eq!(rect(2.0, 3.0, -2.0, -1.0).abs(), rect(0.0, 2.0, 2.0, 1.0));
+        assert_eq!(
+            Rect2::from_corners(Vector2::new(2.0, 0.0), Vector2::new(0.0, 1.0)),
+            rect(0.0, 0.0, 2.0, 1.0)
+        );
+        assert!(rect(0.0, 0.0, 2.0, 0.0).has_no_area());
+    }
+
+    #[test]
+    fn grows_each_side() {
+        let r = rect(0.0, 0.0, 2.0, 2.0);
+        assert_eq!(r.grow(1.0), rect(-1.0, -1.0, 4.0, 4.0));
+        assert_eq!(
+            r.grow_individual(1.0, 2.0, 3.0, 4.0),
+            rect(-1.0, -2.0, 6.0, 8.0)
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use gdext_sys::real;
+
+use crate::{rect2::Rect2, vector2::Vector2, vector2i::Vector2i, PtrCallArg};
+
+/// An axis-aligned 2D rectangle with integer coordinates, given by its top-left `position` and
+/// its `size`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(C)]
+pub struct Rect2i {
+    pub position: Vector2i,
+    pub size: Vector2i,
+}
+
+impl Rect2i {
+    pub fn new(position: Vector2i, size: Vector2i) -> Self {
+        Self { position, size }
+    }
+
+    pub fn end(&self) -> Vector2i {
+        self.position + self.size
+    }
+
+    pub fn center(&self) -> Vector2i {
+        self.position + self.size / 2
+    }
+
+    pub fn area(&self) -> i32 {
+        self.size.x * self.size.y
+    }
+
+    pub fn has_no_area(&self) -> bool {
+        self.size.x <= 0 || self.size.y <= 0
+    }
+
+    /// Returns an equivalent rect with a non-negative size.
+    pub fn abs(&self) -> Self {
+        Self::new(self.position.min(self.end()), self.size.abs())
+    }
+
+    /// Returns `true` if `point` is inside the rect. Points on the right and bottom edges are
+    /// considered outside.
+    pub fn has_point(&self, point: Vector2i) -> bool {
+        point.x >= self.position.x
+            && point.y >= self.position.y
+            && point.x < self.end().x
+            && point.y < self.end().y
+    }
+
+    /// Returns `true` if the rects overlap. Rects that only touch are not overlapping.
+    pub fn intersects(&self, other: &Rect2i) -> bool {
+        self.position.x < other.end().x
+            && self.end().x > other.position.x
+            && self.position.y < other.end().y
+            && self.end().y > other.position.y
+    }
+
+    /// Returns `true` if `other` is completely inside this rect.
+    pub fn encloses(&self, other: &Rect2i) -> bool {
+        other.position.x >= self.position.x
+            && other.position.y >= self.position.y
+            && other.end().x <= self.end().x
+            && other.end().y <= self.end().y
+    }
+
+    /// Returns the overlapping area of both rects, or `None` if they don't intersect.
+    pub fn intersection(&self, other: &Rect2i) -> Option<Rect2i> {
+        if !self.intersects(other) {
+            return None;
+        }
+        let position = self.position.max(other.position);
+        let end = self.end().min(other.end());
+        Some(Self::new(position, end - position))
+    }
+
+    /// Returns the smallest rect enclosing both rects.
+    pub fn merge(&self, other: &Rect2i) -> Rect2i {
+        let position = self.position.min(other.position);
+        let end = self.end().max(other.end());
+        Self::new(position, end - position)
+    }
+
+    /// Returns a copy of the rect expanded to include `point`.
+    pub fn expand(&self, point: Vector2i) -> Rect2i {
+        let position = self.position.min(point);
+        let end = self.end().max(point);
+        Self::new(position, end - position)
+    }
+
+    /// Returns a copy of the rect grown by `by` on all sides.
+    pub fn grow(&self, by: i32) -> Rect2i {
+        self.grow_individual(by, by, by, by)
+    }
+
+    pub fn grow_individual(&self, left: i32, top: i32, right: i32, bottom: i32) -> Rect2i {
+        Self::new(
+            self.position - Vector2i::new(left, top),
+            self.size + Vector2i::new(left + right, top + bottom),
+        )
+    }
+}
+
+impl From<Rect2i> for Rect2 {
+    fn from(r: Rect2i) -> Self {
+        Rect2::new(
+            Vector2::new(r.position.x as real, r.position.y as real),
+            Vector2::new(r.size.x as real, r.size.y as real),
+        )
+    }
+}
+
+impl PtrCallArg for Rect2i {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        *(*arg as *const Rect2i)
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        *(arg as *mut Rect2i) = self;
+    }
+}
+
+const _: () = {
+    use std::mem::{align_of, offset_of, size_of};
+
+    assert!(size_of::<Rect2i>() == 4 * size_of::<i32>());
+    assert!(align_of::<Rect2i>() == align_of::<i32>());
+    assert!(offset_of!(Rect2i, position) == 0);
+    assert!(offset_of!(Rect2i, size) == 2 * size_of::<i32>());
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: i32, y: i32, width: i32, height: i32) -> Rect2i {
+        Rect2i::new(Vector2i::new(x, y), Vector2i::new(width, height))
+    }
+
+    #[test]
+    fn merges_into_the_enclosing_rect() {
+        let a = rect(0, 0, 2, 2);
+        assert_eq!(a.merge(&rect(3, 1, 1, 4)), rect(0, 0, 4, 5));
+        assert_eq!(a.merge(&rect(-3, -1, 1, 1)), rect(-3, -1, 5, 3));
+        assert_eq!(a.merge(&rect(1, 1, 1, 1)), a);
+        assert_eq!(a.merge(&rect(2, 0, 2, 2)), rect(0, 0, 4, 2));
+    }
+
+    #[test]
+    fn touching_rects_dont_intersect() {
+        let a = rect(0, 0, 2, 2);
+        assert!(!a.intersects(&rect(2, 0, 2, 2)));
+        assert!(!a.intersects(&rect(0, 2, 2, 2)));
+        assert!(!a.intersects(&rect(2, 2, 1, 1)));
+        assert_eq!(a.intersection(&rect(2, 0, 2, 2)), None);
+    }
+
+    #[test]
+    fn overlapping_rects_intersect() {
+        let a = rect(0, 0, 2, 2);
+        let b = rect(1, -1, 4, 2);
+        assert!(a.intersects(&b));
+        assert!(b.intersects(&a));
+        assert_eq!(a.intersection(&b), Some(rect(1, 0, 1, 1)));
+    }
+
+    #[test]
+    fn excludes_right_and_bottom_edges() {
+        let r = rect(0, 0, 2, 2);
+        assert!(r.has_point(Vector2i::new(0, 0)));
+        assert!(r.has_point(Vector2i::new(1, 1)));
+        assert!(!r.has_point(Vector2i::new(2, 1)));
+        assert!(!r.has_point(Vector2i::new(1, 2)));
+    }
+
+    #[test]
+    fn rounds_the_center_down() {
+        assert_eq!(rect(0, 0, 3, 5).center(), Vector2i::new(1, 2));
+    }
+
+    #[test]
+    fn expands_and_grows() {
+        let r = rect(0, 0, 2, 2);
+        assert_eq!(r.expand(Vector2i::new(-1, 4)), rect(-1, 0, 3, 4));
+        assert_eq!(r.grow(1), rect(-1, -1, 4, 4));
+        assert_eq!(r.grow_individual(0, 1, 2, 3), rect(0, -1, 4, 6));
+        assert_eq!(rect(2, 2, -2, -1).abs(), rect(0, 1, 2, 1));
+    }
+
+    #[test]
+    fn converts_to_rect2() {
+        let r = Rect2::from(rect(-1, 2, 3, 4));
+        assert_eq!(r.position, Vector2::new(-1.0, 2.0));
+        assert_eq!(r.size, Vector2::new(3.0, 4.0));
+    }
+}
@@ -0,0 +1,166 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::PtrCallArg;
+
+/// An opaque handle to a resource owned by one of Godot's servers, e.g. a canvas item on the
+/// `RenderingServer` or a body on the `PhysicsServer3D`.
+///
+/// The handle is only meaningful to the server that created it. Use [`Rid`] to keep handles from
+/// different servers apart at compile time.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+#[repr(transparent)]
+pub struct RID(u64);
+
+impl RID {
+    /// The invalid handle, which Godot uses to signal "no resource".
+    pub const INVALID: Self = Self(0);
+
+    pub const fn from_u64(id: u64) -> Self {
+        Self(id)
+    }
+
+    pub const fn to_u64(self) -> u64 {
+        self.0
+    }
+
+    pub const fn is_valid(self) -> bool {
+        self.0 != 0
+    }
+
+    /// Tags the handle with the kind of resource it refers to.
+    ///
+    /// Nothing is checked here; the caller asserts that the handle really is a `T`.
+    pub const fn typed<T>(self) -> Rid<T> {
+        Rid {
+            rid: self,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl PtrCallArg for RID {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        *(*arg as *const RID)
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        *(arg as *mut RID) = self;
+    }
+}
+
+/// A [`RID`] tagged with the kind of resource it refers to.
+///
+/// `T` is only a marker and is never instantiated, so it can be an engine class or an empty
+/// type declared just for this purpose:
+///
+/// ```
+/// # use gdext_builtin::rid::{Rid, RID};
+/// pub enum CanvasItem {}
+/// pub enum Texture {}
+///
+/// fn draw(item: Rid<CanvasItem>, texture: Rid<Texture>) {
+///     # let _ = (item, texture);
+///     // ...
+/// }
+///
+/// let item = RID::from_u64(1).typed::<CanvasItem>();
+/// let texture = RID::from_u64(2).typed::<Texture>();
+/// draw(item, texture);
+/// // draw(texture, item); // does not compile
+/// ```
+#[repr(transparent)]
+pub struct Rid<T> {
+    rid: RID,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Rid<T> {
+    pub const INVALID: Self = RID::INVALID.typed();
+
+    pub const fn is_valid(self) -> bool {
+        self.rid.is_valid()
+    }
+
+    /// Returns the untyped handle, e.g. to pass it to an engine API.
+    pub const fn untyped(self) -> RID {
+        self.rid
+    }
+}
+
+impl<T> From<Rid<T>> for RID {
+    fn from(rid: Rid<T>) -> Self {
+        rid.untyped()
+    }
+}
+
+// Implemented by hand, as deriving would put needless bounds on the marker type.
+
+impl<T> Copy for Rid<T> {}
+
+impl<T> Clone for Rid<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Default for Rid<T> {
+    fn default() -> Self {
+        Self::INVALID
+    }
+}
+
+impl<T> PartialEq for Rid<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rid == other.rid
+    }
+}
+
+impl<T> Eq for Rid<T> {}
+
+impl<T> PartialOrd for Rid<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Rid<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rid.cmp(&other.rid)
+    }
+}
+
+impl<T> Hash for Rid<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rid.hash(state)
+    }
+}
+
+impl<T> fmt::Debug for Rid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Rid<{}>({})", std::any::type_name::<T>(), self.rid.0)
+    }
+}
+
+impl<T> PtrCallArg for Rid<T> {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        RID::from_ptr_call_arg(arg).typed()
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        self.rid.to_ptr_call_arg(arg)
+    }
+}
+
+const _: () = {
+    use std::mem::{align_of, size_of};
+
+    assert!(size_of::<RID>() == size_of::<u64>());
+    assert!(align_of::<RID>() == align_of::<u64>());
+    assert!(size_of::<Rid<()>>() == size_of::<u64>());
+};
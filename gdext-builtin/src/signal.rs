@@ -0,0 +1,225 @@
+use std::{fmt, mem::MaybeUninit};
+
+use gdext_sys::{self as sys, interface_fn, lazy::Lazy};
+
+use crate::{
+    callable::Callable,
+    string_name::StringName,
+    variant::{CallError, Variant},
+    PtrCallArg,
+};
+
+/// A signal of a specific object, e.g. what GDScript evaluates `some_node.some_signal` to.
+#[repr(C, align(8))]
+pub struct Signal(MaybeUninit<[u8; 16]>);
+
+impl Signal {
+    fn uninit() -> Self {
+        Self(MaybeUninit::uninit())
+    }
+
+    #[doc(hidden)]
+    pub fn as_mut_ptr(&mut self) -> sys::GDNativeTypePtr {
+        self.0.as_mut_ptr() as *mut _
+    }
+    #[doc(hidden)]
+    pub fn as_ptr(&self) -> sys::GDNativeTypePtr {
+        self.0.as_ptr() as *mut _
+    }
+
+    /// Creates a null signal, which is not bound to any object.
+    pub fn new() -> Self {
+        unsafe {
+            let mut s = Self::uninit();
+
+            static CONSTR: Lazy<
+                unsafe extern "C" fn(sys::GDNativeTypePtr, *const sys::GDNativeTypePtr),
+            > = Lazy::new(|| unsafe {
+                interface_fn!(variant_get_ptr_constructor)(
+                    sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_SIGNAL,
+                    0,
+                )
+                .unwrap()
+            });
+            CONSTR(s.as_mut_ptr(), std::ptr::null());
+            s
+        }
+    }
+
+    /// Creates a reference to the signal `name` of `object`.
+    ///
+    /// # Safety
+    ///
+    /// `object` must point to a live engine object.
+    pub unsafe fn from_object_signal(
+        object: sys::GDNativeObjectPtr,
+        name: &str,
+    ) -> Result<Self, CallError> {
+        let args = [
+            Variant::from_object_ptr(object),
+            Variant::from(StringName::from(name)),
+        ];
+        let args = args.iter().map(Variant::as_ptr).collect::<Vec<_>>();
+
+        let mut ret = Variant::nil();
+        let mut err = sys::GDNativeCallError {
+            error: sys::GDNativeCallErrorType_GDNATIVE_CALL_OK,
+            argument: 0,
+            expected: 0,
+        };
+        interface_fn!(variant_construct)(
+            sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_SIGNAL,
+            ret.as_mut_ptr(),
+            args.as_ptr(),
+            args.len() as _,
+            &mut err,
+        );
+
+        if err.error == sys::GDNativeCallErrorType_GDNATIVE_CALL_OK {
+            Ok(Signal::from(&ret))
+        } else {
+            Err(CallError(err))
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.variant_call("is_null", &[])
+            .map_or(true, |ret| bool::from(&ret))
+    }
+
+    pub fn name(&self) -> StringName {
+        self.variant_call("get_name", &[])
+            .map(|ret| StringName::from(&ret))
+            .unwrap_or_default()
+    }
+
+    pub fn object_id(&self) -> u64 {
+        self.variant_call("get_object_id", &[])
+            .map_or(0, |ret| u64::from(&ret))
+    }
+
+    /// Connects `callable` to this signal, with `flags` being a combination of Godot's
+    /// `Object.ConnectFlags`.
+    ///
+    /// When Godot refuses the connection, e.g. because the signal doesn't exist or `callable` is
+    /// already connected, it prints why and the error holds the `Error` value it returned.
+    pub fn connect(&self, callable: &Callable, flags: u32) -> Result<(), SignalError> {
+        let ret = self.variant_call("connect", &[Variant::from(callable), Variant::from(flags)])?;
+        match i64::from(&ret) {
+            0 => Ok(()),
+            error => Err(SignalError::Godot(error)),
+        }
+    }
+
+    /// Disconnects `callable` from this signal.
+    pub fn disconnect(&self, callable: &Callable) -> Result<(), SignalError> {
+        // Godot doesn't report whether `disconnect` did anything, only prints an error if not.
+        if !self.is_connected(callable) {
+            return Err(SignalError::NotConnected);
+        }
+        self.variant_call("disconnect", &[Variant::from(callable)])?;
+        Ok(())
+    }
+
+    pub fn is_connected(&self, callable: &Callable) -> bool {
+        self.variant_call("is_connected", &[Variant::from(callable)])
+            .is_ok_and(|ret| bool::from(&ret))
+    }
+
+    /// Emits the signal, calling all connected callables with `args`.
+    pub fn emit(&self, args: &[Variant]) -> Result<(), CallError> {
+        self.variant_call("emit", args).map(|_| ())
+    }
+
+    /// Returns the connections of this signal, as an `Array` of `Dictionary` entries with
+    /// `signal`, `callable` and `flags` keys.
+    pub fn get_connections(&self) -> Variant {
+        self.variant_call("get_connections", &[])
+            .unwrap_or_else(|_| Variant::nil())
+    }
+
+    fn variant_call(&self, method: &str, args: &[Variant]) -> Result<Variant, CallError> {
+        Variant::from(self).call(method, args)
+    }
+}
+
+/// The reason [`Signal::connect`] or [`Signal::disconnect`] failed.
+#[derive(Copy, Clone, Debug)]
+pub enum SignalError {
+    /// The call into the signal failed, e.g. because it's null.
+    Call(CallError),
+    /// Godot refused the connection, returning this value of its `Error` enum, e.g. 31
+    /// (`ERR_INVALID_PARAMETER`) for an already connected callable.
+    Godot(i64),
+    /// The callable to disconnect isn't connected to the signal.
+    NotConnected,
+}
+
+impl From<CallError> for SignalError {
+    fn from(err: CallError) -> Self {
+        Self::Call(err)
+    }
+}
+
+impl fmt::Display for SignalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Call(err) => err.fmt(f),
+            Self::Godot(error) => write!(f, "Godot error {error}"),
+            Self::NotConnected => write!(f, "callable is not connected"),
+        }
+    }
+}
+
+impl std::error::Error for SignalError {}
+
+impl Default for Signal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for Signal {
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut s = Self::uninit();
+
+            static CONSTR: Lazy<
+                unsafe extern "C" fn(sys::GDNativeTypePtr, *const sys::GDNativeTypePtr),
+            > = Lazy::new(|| unsafe {
+                interface_fn!(variant_get_ptr_constructor)(
+                    sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_SIGNAL,
+                    1,
+                )
+                .unwrap()
+            });
+            let args = [self.as_ptr()];
+            CONSTR(s.as_mut_ptr(), args.as_ptr());
+            s
+        }
+    }
+}
+
+impl Drop for Signal {
+    fn drop(&mut self) {
+        unsafe {
+            static DESTR: Lazy<unsafe extern "C" fn(sys::GDNativeTypePtr)> = Lazy::new(|| unsafe {
+                interface_fn!(variant_get_ptr_destructor)(
+                    sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_SIGNAL,
+                )
+                .unwrap()
+            });
+            DESTR(self.as_mut_ptr());
+        }
+    }
+}
+
+impl PtrCallArg for Signal {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        Clone::clone(&*(*arg as *const Signal))
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        std::ptr::write(arg as *mut Signal, self);
+    }
+}
@@ -0,0 +1,52 @@
+//! Counts of the engine values held by Rust, kept in debug builds to find leaks.
+
+#[cfg(debug_assertions)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub(crate) struct Counter {
+    #[cfg(debug_assertions)]
+    live: AtomicUsize,
+}
+
+impl Counter {
+    const fn new() -> Self {
+        Self {
+            #[cfg(debug_assertions)]
+            live: AtomicUsize::new(0),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn acquire(&self) {
+        #[cfg(debug_assertions)]
+        self.live.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(crate) fn release(&self) {
+        #[cfg(debug_assertions)]
+        self.live.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    fn get(&self) -> Option<usize> {
+        #[cfg(debug_assertions)]
+        return Some(self.live.load(Ordering::Relaxed));
+        #[cfg(not(debug_assertions))]
+        None
+    }
+}
+
+pub(crate) static VARIANTS: Counter = Counter::new();
+pub(crate) static STRINGS: Counter = Counter::new();
+
+/// The number of [`Variant`](crate::variant::Variant)s alive on the Rust side, or `None` in
+/// release builds, which don't count them.
+pub fn live_variants() -> Option<usize> {
+    VARIANTS.get()
+}
+
+/// The number of [`GodotString`](crate::string::GodotString)s alive on the Rust side, or `None`
+/// in release builds, which don't count them.
+pub fn live_strings() -> Option<usize> {
+    STRINGS.get()
+}
@@ -1,9 +1,8 @@
 use std::{convert::Infallible, mem::MaybeUninit, str::FromStr};
 
-use gdext_sys::{self as sys, interface_fn};
-use once_cell::sync::Lazy;
+use gdext_sys::{self as sys, interface_fn, lazy::Lazy};
 
-use crate::PtrCallArg;
+use crate::{stats, PtrCallArg};
 
 #[cfg(target_pointer_width = "32")]
 const SIZE_IN_BYTES: u64 = 4;
@@ -15,6 +14,7 @@ pub struct GodotString(MaybeUninit<[u8; SIZE_IN_BYTES as usize]>);
 
 impl GodotString {
     fn uninit() -> Self {
+        stats::STRINGS.acquire();
         Self(MaybeUninit::uninit())
     }
 
@@ -132,15 +132,18 @@ impl Drop for GodotString {
             });
             DESTR(self.as_mut_ptr());
         }
+        stats::STRINGS.release();
     }
 }
 
 impl PtrCallArg for GodotString {
     unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
-        Clone::clone(&*(arg as *mut GodotString))
+        Clone::clone(&*(*arg as *const GodotString))
     }
 
     unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        // The engine owns the string from now on.
+        stats::STRINGS.release();
         std::ptr::write(arg as *mut GodotString, self);
     }
 }
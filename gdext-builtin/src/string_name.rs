@@ -0,0 +1,160 @@
+use std::{fmt, mem::MaybeUninit};
+
+use gdext_sys::{self as sys, interface_fn, lazy::Lazy};
+
+use crate::{string::GodotString, PtrCallArg};
+
+#[cfg(target_pointer_width = "32")]
+const SIZE_IN_BYTES: u64 = 4;
+#[cfg(target_pointer_width = "64")]
+const SIZE_IN_BYTES: u64 = 8;
+
+/// An interned string, used by Godot for method, signal and property names.
+#[repr(C, align(8))]
+pub struct StringName(MaybeUninit<[u8; SIZE_IN_BYTES as usize]>);
+
+impl StringName {
+    fn uninit() -> Self {
+        Self(MaybeUninit::uninit())
+    }
+
+    #[doc(hidden)]
+    pub fn as_mut_ptr(&mut self) -> sys::GDNativeStringNamePtr {
+        self.0.as_mut_ptr() as *mut _
+    }
+    #[doc(hidden)]
+    pub fn as_ptr(&self) -> sys::GDNativeStringNamePtr {
+        self.0.as_ptr() as *mut _
+    }
+
+    pub fn new() -> Self {
+        unsafe {
+            let mut s = Self::uninit();
+
+            static CONSTR: Lazy<
+                unsafe extern "C" fn(sys::GDNativeTypePtr, *const sys::GDNativeTypePtr),
+            > = Lazy::new(|| unsafe {
+                interface_fn!(variant_get_ptr_constructor)(
+                    sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_STRING_NAME,
+                    0,
+                )
+                .unwrap()
+            });
+            CONSTR(s.as_mut_ptr(), std::ptr::null());
+            s
+        }
+    }
+
+    pub fn from_godot_string(s: &GodotString) -> Self {
+        unsafe {
+            let mut name = Self::uninit();
+
+            static CONSTR: Lazy<
+                unsafe extern "C" fn(sys::GDNativeTypePtr, *const sys::GDNativeTypePtr),
+            > = Lazy::new(|| unsafe {
+                interface_fn!(variant_get_ptr_constructor)(
+                    sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_STRING_NAME,
+                    2,
+                )
+                .unwrap()
+            });
+            let args = [s.as_ptr() as sys::GDNativeTypePtr];
+            CONSTR(name.as_mut_ptr(), args.as_ptr());
+            name
+        }
+    }
+
+    pub fn to_godot_string(&self) -> GodotString {
+        unsafe {
+            let mut s = GodotString::new();
+
+            static CONSTR: Lazy<
+                unsafe extern "C" fn(sys::GDNativeTypePtr, *const sys::GDNativeTypePtr),
+            > = Lazy::new(|| unsafe {
+                interface_fn!(variant_get_ptr_constructor)(
+                    sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_STRING,
+                    2,
+                )
+                .unwrap()
+            });
+            let args = [self.as_ptr() as sys::GDNativeTypePtr];
+            CONSTR(s.as_mut_ptr(), args.as_ptr());
+            s
+        }
+    }
+}
+
+impl Default for StringName {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for StringName {
+    fn clone(&self) -> Self {
+        unsafe {
+            let mut s = Self::uninit();
+
+            static CONSTR: Lazy<
+                unsafe extern "C" fn(sys::GDNativeTypePtr, *const sys::GDNativeTypePtr),
+            > = Lazy::new(|| unsafe {
+                interface_fn!(variant_get_ptr_constructor)(
+                    sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_STRING_NAME,
+                    1,
+                )
+                .unwrap()
+            });
+            let args = [self.as_ptr() as sys::GDNativeTypePtr];
+            CONSTR(s.as_mut_ptr(), args.as_ptr());
+            s
+        }
+    }
+}
+
+impl From<&str> for StringName {
+    fn from(s: &str) -> Self {
+        Self::from_godot_string(&GodotString::from(s))
+    }
+}
+
+impl From<&GodotString> for StringName {
+    fn from(s: &GodotString) -> Self {
+        Self::from_godot_string(s)
+    }
+}
+
+impl fmt::Display for StringName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_godot_string(), f)
+    }
+}
+
+impl fmt::Debug for StringName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "&{:?}", self.to_string())
+    }
+}
+
+impl Drop for StringName {
+    fn drop(&mut self) {
+        unsafe {
+            static DESTR: Lazy<unsafe extern "C" fn(sys::GDNativeTypePtr)> = Lazy::new(|| unsafe {
+                interface_fn!(variant_get_ptr_destructor)(
+                    sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_STRING_NAME,
+                )
+                .unwrap()
+            });
+            DESTR(self.as_mut_ptr());
+        }
+    }
+}
+
+impl PtrCallArg for StringName {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        Clone::clone(&*(*arg as *const StringName))
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        std::ptr::write(arg as *mut StringName, self);
+    }
+}
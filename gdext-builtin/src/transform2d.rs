@@ -0,0 +1,180 @@
+use std::ops::Mul;
+
+use gdext_sys::real;
+
+use crate::{vector2::Vector2, PtrCallArg};
+
+#[cfg(not(feature = "real_is_double"))]
+type GlamAffine2 = glam::f32::Affine2;
+#[cfg(feature = "real_is_double")]
+type GlamAffine2 = glam::f64::DAffine2;
+
+/// A 2D affine transform, stored like Godot's `Transform2D` as the two basis columns `x` and `y`
+/// followed by the translation `origin`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub struct Transform2D {
+    pub x: Vector2,
+    pub y: Vector2,
+    pub origin: Vector2,
+}
+
+impl Transform2D {
+    pub const IDENTITY: Self = Self {
+        x: Vector2::X,
+        y: Vector2::Y,
+        origin: Vector2::ZERO,
+    };
+
+    pub fn new(x: Vector2, y: Vector2, origin: Vector2) -> Self {
+        Self { x, y, origin }
+    }
+
+    pub fn from_angle_origin(angle: real, origin: Vector2) -> Self {
+        GlamAffine2::from_angle_translation(angle, origin).into()
+    }
+
+    /// Returns the transform of a point, i.e. including the translation.
+    pub fn xform(&self, v: Vector2) -> Vector2 {
+        self.basis_xform(v) + self.origin
+    }
+
+    /// Returns the transform of a direction, i.e. ignoring the translation.
+    pub fn basis_xform(&self, v: Vector2) -> Vector2 {
+        self.x * v.x + self.y * v.y
+    }
+
+    pub fn affine_inverse(&self) -> Self {
+        GlamAffine2::from(*self).inverse().into()
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<GlamAffine2> for Transform2D {
+    fn from(a: GlamAffine2) -> Self {
+        let [x, y, origin] = a.to_cols_array_2d();
+        Self::new(x.into(), y.into(), origin.into())
+    }
+}
+
+impl From<Transform2D> for GlamAffine2 {
+    fn from(t: Transform2D) -> Self {
+        GlamAffine2::from_cols(t.x, t.y, t.origin)
+    }
+}
+
+impl Mul<Vector2> for Transform2D {
+    type Output = Vector2;
+
+    fn mul(self, v: Vector2) -> Vector2 {
+        self.xform(v)
+    }
+}
+
+impl Mul for Transform2D {
+    type Output = Transform2D;
+
+    fn mul(self, other: Transform2D) -> Transform2D {
+        Self::new(
+            self.basis_xform(other.x),
+            self.basis_xform(other.y),
+            self.xform(other.origin),
+        )
+    }
+}
+
+impl PtrCallArg for Transform2D {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        *(*arg as *const Transform2D)
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        *(arg as *mut Transform2D) = self;
+    }
+}
+
+const _: () = {
+    use std::mem::{align_of, offset_of, size_of};
+
+    assert!(size_of::<Transform2D>() == 6 * size_of::<real>());
+    assert!(align_of::<Transform2D>() == align_of::<real>());
+    assert!(offset_of!(Transform2D, x) == 0);
+    assert!(offset_of!(Transform2D, y) == 2 * size_of::<real>());
+    assert!(offset_of!(Transform2D, origin) == 4 * size_of::<real>());
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAC_PI_2: real = std::f64::consts::FRAC_PI_2 as real;
+
+    fn assert_close(a: Transform2D, b: Transform2D) {
+        assert!(
+            a.x.abs_diff_eq(b.x, 1e-5)
+                && a.y.abs_diff_eq(b.y, 1e-5)
+                && a.origin.abs_diff_eq(b.origin, 1e-5),
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn transforms_points_and_directions() {
+        // Transform2D(PI / 2, Vector2(1, 2)) in Godot.
+        let t = Transform2D::from_angle_origin(FRAC_PI_2, Vector2::new(1.0, 2.0));
+        assert!((t * Vector2::X).abs_diff_eq(Vector2::new(1.0, 3.0), 1e-5));
+        assert!(t.basis_xform(Vector2::X).abs_diff_eq(Vector2::Y, 1e-5));
+    }
+
+    #[test]
+    fn applies_the_right_operand_first() {
+        let scale = Transform2D::new(Vector2::new(2.0, 0.0), Vector2::Y, Vector2::new(1.0, 0.0));
+        let translation = Transform2D::new(Vector2::X, Vector2::Y, Vector2::new(3.0, 4.0));
+        let t = scale * translation;
+        assert_close(
+            t,
+            Transform2D::new(Vector2::new(2.0, 0.0), Vector2::Y, Vector2::new(7.0, 4.0)),
+        );
+
+        let v = Vector2::new(-1.0, 5.0);
+        assert!((t * v).abs_diff_eq(scale * (translation * v), 1e-5));
+    }
+
+    #[test]
+    fn inverts() {
+        let t = Transform2D::new(
+            Vector2::new(2.0, 0.0),
+            Vector2::new(0.0, 4.0),
+            Vector2::new(2.0, 4.0),
+        );
+        assert_close(
+            t.affine_inverse(),
+            Transform2D::new(
+                Vector2::new(0.5, 0.0),
+                Vector2::new(0.0, 0.25),
+                Vector2::new(-1.0, -1.0),
+            ),
+        );
+
+        let t = Transform2D::from_angle_origin(0.3, Vector2::new(-5.0, 2.0));
+        assert_close(t * t.affine_inverse(), Transform2D::IDENTITY);
+        assert_close(t.affine_inverse() * t, Transform2D::IDENTITY);
+    }
+
+    #[test]
+    fn converts_to_glam_and_back() {
+        let t = Transform2D::new(
+            Vector2::new(1.0, 2.0),
+            Vector2::new(3.0, 4.0),
+            Vector2::new(5.0, 6.0),
+        );
+        let affine = GlamAffine2::from(t);
+        assert_eq!(affine.translation, t.origin);
+        assert_eq!(Transform2D::from(affine), t);
+    }
+}
@@ -0,0 +1,178 @@
+use std::ops::Mul;
+
+use gdext_sys::real;
+
+use crate::{basis::Basis, vector3::Vector3, PtrCallArg};
+
+#[cfg(not(feature = "real_is_double"))]
+type GlamAffine3 = glam::f32::Affine3A;
+#[cfg(feature = "real_is_double")]
+type GlamAffine3 = glam::f64::DAffine3;
+
+/// A 3D affine transform, made up of a [`Basis`] followed by the translation `origin`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub struct Transform3D {
+    pub basis: Basis,
+    pub origin: Vector3,
+}
+
+impl Transform3D {
+    pub const IDENTITY: Self = Self {
+        basis: Basis::IDENTITY,
+        origin: Vector3::ZERO,
+    };
+
+    pub fn new(basis: Basis, origin: Vector3) -> Self {
+        Self { basis, origin }
+    }
+
+    pub fn from_translation(origin: Vector3) -> Self {
+        Self::new(Basis::IDENTITY, origin)
+    }
+
+    pub fn affine_inverse(&self) -> Self {
+        GlamAffine3::from(*self).inverse().into()
+    }
+}
+
+impl Default for Transform3D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<GlamAffine3> for Transform3D {
+    fn from(a: GlamAffine3) -> Self {
+        let [x, y, z, origin] = a.to_cols_array_2d();
+        Self::new(
+            Basis::from_cols(x.into(), y.into(), z.into()),
+            origin.into(),
+        )
+    }
+}
+
+impl From<Transform3D> for GlamAffine3 {
+    fn from(t: Transform3D) -> Self {
+        GlamAffine3::from_cols_array_2d(&[
+            t.basis.col(0).to_array(),
+            t.basis.col(1).to_array(),
+            t.basis.col(2).to_array(),
+            t.origin.to_array(),
+        ])
+    }
+}
+
+impl Mul<Vector3> for Transform3D {
+    type Output = Vector3;
+
+    fn mul(self, v: Vector3) -> Vector3 {
+        self.basis * v + self.origin
+    }
+}
+
+impl Mul for Transform3D {
+    type Output = Transform3D;
+
+    fn mul(self, other: Transform3D) -> Transform3D {
+        Self::new(self.basis * other.basis, self * other.origin)
+    }
+}
+
+impl PtrCallArg for Transform3D {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        *(*arg as *const Transform3D)
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        *(arg as *mut Transform3D) = self;
+    }
+}
+
+const _: () = {
+    use std::mem::{align_of, offset_of, size_of};
+
+    assert!(size_of::<Transform3D>() == 12 * size_of::<real>());
+    assert!(align_of::<Transform3D>() == align_of::<real>());
+    assert!(offset_of!(Transform3D, basis) == 0);
+    assert!(offset_of!(Transform3D, origin) == 9 * size_of::<real>());
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quaternion::Quaternion;
+
+    const FRAC_PI_2: real = std::f64::consts::FRAC_PI_2 as real;
+
+    fn assert_close(a: Transform3D, b: Transform3D) {
+        let close = (0..3).all(|i| a.basis.rows[i].abs_diff_eq(b.basis.rows[i], 1e-5))
+            && a.origin.abs_diff_eq(b.origin, 1e-5);
+        assert!(close, "{a:?} != {b:?}");
+    }
+
+    fn rotation_y(angle: real) -> Basis {
+        Basis::from_quaternion(Quaternion::from_axis_angle(Vector3::Y, angle))
+    }
+
+    #[test]
+    fn transforms_points() {
+        // Transform3D(Basis(Vector3.UP, PI / 2), Vector3(1, 2, 3)) * Vector3.RIGHT in Godot.
+        let t = Transform3D::new(rotation_y(FRAC_PI_2), Vector3::new(1.0, 2.0, 3.0));
+        assert!((t * Vector3::X).abs_diff_eq(Vector3::new(1.0, 2.0, 2.0), 1e-5));
+        assert_eq!(
+            Transform3D::from_translation(Vector3::ONE) * Vector3::Z,
+            Vector3::new(1.0, 1.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn applies_the_right_operand_first() {
+        let rotation = Transform3D::new(rotation_y(FRAC_PI_2), Vector3::ZERO);
+        let translation = Transform3D::from_translation(Vector3::new(1.0, 0.0, 0.0));
+
+        // Rotating the translation: the origin moves to (0, 0, -1).
+        let t = rotation * translation;
+        assert!(t.origin.abs_diff_eq(Vector3::new(0.0, 0.0, -1.0), 1e-5));
+        // Translating the rotation: the origin stays at (1, 0, 0).
+        let t = translation * rotation;
+        assert!(t.origin.abs_diff_eq(Vector3::new(1.0, 0.0, 0.0), 1e-5));
+
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        assert!(((rotation * translation) * v).abs_diff_eq(rotation * (translation * v), 1e-5));
+    }
+
+    #[test]
+    fn inverts() {
+        let t = Transform3D::new(
+            Basis::from_cols(Vector3::X * 2.0, Vector3::Y * 2.0, Vector3::Z * 2.0),
+            Vector3::new(2.0, 0.0, 0.0),
+        );
+        assert_close(
+            t.affine_inverse(),
+            Transform3D::new(
+                Basis::from_cols(Vector3::X * 0.5, Vector3::Y * 0.5, Vector3::Z * 0.5),
+                Vector3::new(-1.0, 0.0, 0.0),
+            ),
+        );
+
+        let t = Transform3D::new(rotation_y(0.4), Vector3::new(3.0, -1.0, 2.0));
+        assert_close(t * t.affine_inverse(), Transform3D::IDENTITY);
+        assert_close(t.affine_inverse() * t, Transform3D::IDENTITY);
+    }
+
+    #[test]
+    fn converts_to_glam_and_back() {
+        let t = Transform3D::new(
+            Basis::from_cols(
+                Vector3::new(1.0, 2.0, 3.0),
+                Vector3::new(4.0, 5.0, 6.0),
+                Vector3::new(7.0, 8.0, 10.0),
+            ),
+            Vector3::new(11.0, 12.0, 13.0),
+        );
+        let affine = GlamAffine3::from(t);
+        assert_eq!(Vector3::from(affine.translation), t.origin);
+        assert_eq!(Transform3D::from(affine), t);
+    }
+}
@@ -1,6 +1,8 @@
-use std::mem::MaybeUninit;
+use std::{fmt, mem::MaybeUninit};
 
-use gdext_sys::{self as sys, interface_fn};
+use gdext_sys::{self as sys, interface_fn, lazy::Lazy};
+
+use crate::{stats, string_name::StringName};
 
 #[cfg(not(feature = "real_is_double"))]
 const SIZE_IN_BYTES: u64 = 24;
@@ -13,6 +15,7 @@ pub struct Variant(MaybeUninit<[u8; SIZE_IN_BYTES as usize]>);
 impl Variant {
     #[doc(hidden)]
     pub fn uninit() -> Self {
+        stats::VARIANTS.acquire();
         Self(MaybeUninit::uninit())
     }
 
@@ -33,8 +36,161 @@ impl Variant {
             v
         }
     }
+
+    pub fn get_type(&self) -> sys::GDNativeVariantType {
+        unsafe { interface_fn!(variant_get_type)(self.as_ptr()) }
+    }
+
+    pub fn is_nil(&self) -> bool {
+        self.get_type() == sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_NIL
+    }
+
+    /// Wraps an engine object in a variant.
+    ///
+    /// # Safety
+    ///
+    /// `object` must be null or point to a live engine object.
+    #[doc(hidden)]
+    pub unsafe fn from_object_ptr(object: sys::GDNativeObjectPtr) -> Self {
+        sys::debug_assert_main_thread();
+
+        static CONSTR: Lazy<unsafe extern "C" fn(sys::GDNativeVariantPtr, sys::GDNativeTypePtr)> =
+            Lazy::new(|| unsafe {
+                interface_fn!(get_variant_from_type_constructor)(
+                    sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_OBJECT,
+                )
+                .unwrap()
+            });
+        let mut v = Variant::uninit();
+        CONSTR(v.as_mut_ptr(), &object as *const _ as *mut _);
+        v
+    }
+
+    /// Returns the engine object held by this variant, or null if it doesn't hold one.
+    #[doc(hidden)]
+    pub fn to_object_ptr(&self) -> sys::GDNativeObjectPtr {
+        if self.get_type() != sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_OBJECT {
+            return std::ptr::null_mut();
+        }
+        unsafe {
+            static CONSTR: Lazy<
+                unsafe extern "C" fn(sys::GDNativeTypePtr, sys::GDNativeVariantPtr),
+            > = Lazy::new(|| unsafe {
+                interface_fn!(get_variant_to_type_constructor)(
+                    sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_OBJECT,
+                )
+                .unwrap()
+            });
+            let mut object: sys::GDNativeObjectPtr = std::ptr::null_mut();
+            CONSTR(&mut object as *mut _ as *mut _, self.as_ptr());
+            object
+        }
+    }
+
+    /// Calls the method `method` on the value held by this variant, like GDScript's dynamic
+    /// calls do.
+    pub fn call(&self, method: &str, args: &[Variant]) -> Result<Variant, CallError> {
+        if self.get_type() == sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_OBJECT {
+            sys::debug_assert_main_thread();
+        }
+
+        let method = StringName::from(method);
+        let args = args.iter().map(Variant::as_ptr).collect::<Vec<_>>();
+
+        unsafe {
+            let mut ret = Variant::uninit();
+            let mut err = sys::GDNativeCallError {
+                error: sys::GDNativeCallErrorType_GDNATIVE_CALL_OK,
+                argument: 0,
+                expected: 0,
+            };
+            interface_fn!(variant_call)(
+                self.as_ptr(),
+                method.as_ptr(),
+                args.as_ptr(),
+                args.len() as _,
+                ret.as_mut_ptr(),
+                &mut err,
+            );
+
+            if err.error == sys::GDNativeCallErrorType_GDNATIVE_CALL_OK {
+                Ok(ret)
+            } else {
+                Err(CallError(err))
+            }
+        }
+    }
+
+    /// Returns the element at `index` of the array held by this variant, like GDScript's
+    /// `value[index]`, or `None` if it doesn't hold an array or `index` is out of bounds.
+    pub fn get_indexed(&self, index: i64) -> Option<Variant> {
+        unsafe {
+            let mut ret = Variant::uninit();
+            let mut valid = 0;
+            let mut oob = 0;
+            interface_fn!(variant_get_indexed)(
+                self.as_ptr(),
+                index,
+                ret.as_mut_ptr(),
+                &mut valid,
+                &mut oob,
+            );
+            (valid != 0 && oob == 0).then_some(ret)
+        }
+    }
+
+    /// Returns the value at `key` of the dictionary (or object) held by this variant, like
+    /// GDScript's `value[key]`, or `None` if there is none.
+    pub fn get_keyed(&self, key: &Variant) -> Option<Variant> {
+        unsafe {
+            let mut ret = Variant::uninit();
+            let mut valid = 0;
+            interface_fn!(variant_get_keyed)(
+                self.as_ptr(),
+                key.as_ptr(),
+                ret.as_mut_ptr(),
+                &mut valid,
+            );
+            (valid != 0).then_some(ret)
+        }
+    }
+}
+
+/// The reason a dynamic call through [`Variant::call`] failed.
+#[derive(Copy, Clone, Debug)]
+pub struct CallError(pub sys::GDNativeCallError);
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sys::GDNativeCallError {
+            error,
+            argument,
+            expected,
+        } = self.0;
+        match error {
+            sys::GDNativeCallErrorType_GDNATIVE_CALL_ERROR_INVALID_METHOD => {
+                write!(f, "invalid method")
+            }
+            sys::GDNativeCallErrorType_GDNATIVE_CALL_ERROR_INVALID_ARGUMENT => write!(
+                f,
+                "invalid argument {argument}, expected variant type {expected}"
+            ),
+            sys::GDNativeCallErrorType_GDNATIVE_CALL_ERROR_TOO_MANY_ARGUMENTS => {
+                write!(f, "too many arguments, expected {expected}")
+            }
+            sys::GDNativeCallErrorType_GDNATIVE_CALL_ERROR_TOO_FEW_ARGUMENTS => {
+                write!(f, "too few arguments, expected {expected}")
+            }
+            sys::GDNativeCallErrorType_GDNATIVE_CALL_ERROR_INSTANCE_IS_NULL => {
+                write!(f, "instance is null")
+            }
+            _ => write!(f, "call error {error}"),
+        }
+    }
 }
 
+impl std::error::Error for CallError {}
+
 impl Clone for Variant {
     fn clone(&self) -> Self {
         unsafe {
@@ -50,14 +206,32 @@ impl Drop for Variant {
         unsafe {
             interface_fn!(variant_destroy)(self.as_ptr());
         }
+        stats::VARIANTS.release();
     }
 }
 
 mod conversions {
-    use gdext_sys::{self as sys, interface_fn};
-    use once_cell::sync::Lazy;
+    use gdext_sys::{self as sys, interface_fn, lazy::Lazy};
 
-    use crate::{string::GodotString, vector2::Vector2, vector3::Vector3};
+    use crate::{
+        aabb::AABB,
+        basis::Basis,
+        callable::Callable,
+        plane::Plane,
+        quaternion::Quaternion,
+        rect2::Rect2,
+        rect2i::Rect2i,
+        rid::{Rid, RID},
+        signal::Signal,
+        string::GodotString,
+        string_name::StringName,
+        transform2d::Transform2D,
+        transform3d::Transform3D,
+        vector2::Vector2,
+        vector2i::Vector2i,
+        vector3::Vector3,
+        vector3i::Vector3i,
+    };
 
     use super::Variant;
 
@@ -158,75 +332,123 @@ mod conversions {
     from_int!(i16);
     from_int!(i32);
 
-    impl From<Vector2> for Variant {
-        fn from(vec: Vector2) -> Self {
+    impl From<f64> for Variant {
+        fn from(f: f64) -> Self {
             unsafe {
                 static CONSTR: Lazy<
                     unsafe extern "C" fn(sys::GDNativeVariantPtr, sys::GDNativeTypePtr),
                 > = Lazy::new(|| unsafe {
                     interface_fn!(get_variant_from_type_constructor)(
-                        sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VECTOR2,
+                        sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_FLOAT,
                     )
                     .unwrap()
                 });
                 let mut v = Variant::uninit();
-                CONSTR(v.as_mut_ptr(), &vec as *const _ as *mut _);
+                CONSTR(v.as_mut_ptr(), &f as *const _ as *mut _);
                 v
             }
         }
     }
 
-    impl From<&Variant> for Vector2 {
+    impl From<&Variant> for f64 {
         fn from(v: &Variant) -> Self {
             unsafe {
                 static CONSTR: Lazy<
                     unsafe extern "C" fn(sys::GDNativeTypePtr, sys::GDNativeVariantPtr),
                 > = Lazy::new(|| unsafe {
                     interface_fn!(get_variant_to_type_constructor)(
-                        sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VECTOR2,
+                        sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_FLOAT,
                     )
                     .unwrap()
                 });
-                let mut vec = Vector2::ZERO;
-                CONSTR(&mut vec as *mut _ as *mut _, v.as_ptr());
-                vec
+                let mut res = 0.0;
+                CONSTR(&mut res as *mut _ as *mut _, v.as_ptr());
+                res
             }
         }
     }
 
-    impl From<Vector3> for Variant {
-        fn from(vec: Vector3) -> Self {
-            unsafe {
-                static CONSTR: Lazy<
-                    unsafe extern "C" fn(sys::GDNativeVariantPtr, sys::GDNativeTypePtr),
-                > = Lazy::new(|| unsafe {
-                    interface_fn!(get_variant_from_type_constructor)(
-                        sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VECTOR3,
-                    )
-                    .unwrap()
-                });
-                let mut v = Variant::uninit();
-                CONSTR(v.as_mut_ptr(), &vec as *const _ as *mut _);
-                v
-            }
+    impl From<f32> for Variant {
+        fn from(f: f32) -> Self {
+            Variant::from(f as f64)
         }
     }
 
-    impl From<&Variant> for Vector3 {
+    impl From<&Variant> for f32 {
         fn from(v: &Variant) -> Self {
-            unsafe {
-                static CONSTR: Lazy<
-                    unsafe extern "C" fn(sys::GDNativeTypePtr, sys::GDNativeVariantPtr),
-                > = Lazy::new(|| unsafe {
-                    interface_fn!(get_variant_to_type_constructor)(
-                        sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VECTOR3,
-                    )
-                    .unwrap()
-                });
-                let mut vec = Vector3::ZERO;
-                CONSTR(&mut vec as *mut _ as *mut _, v.as_ptr());
-                vec
+            f64::from(v) as f32
+        }
+    }
+
+    macro_rules! impl_variant_conversions {
+        ($t:ty, $variant_type:ident) => {
+            impl From<$t> for Variant {
+                fn from(value: $t) -> Self {
+                    unsafe {
+                        static CONSTR: Lazy<
+                            unsafe extern "C" fn(sys::GDNativeVariantPtr, sys::GDNativeTypePtr),
+                        > = Lazy::new(|| unsafe {
+                            interface_fn!(get_variant_from_type_constructor)(sys::$variant_type)
+                                .unwrap()
+                        });
+                        let mut v = Variant::uninit();
+                        CONSTR(v.as_mut_ptr(), &value as *const _ as *mut _);
+                        v
+                    }
+                }
             }
+
+            impl From<&Variant> for $t {
+                fn from(v: &Variant) -> Self {
+                    unsafe {
+                        static CONSTR: Lazy<
+                            unsafe extern "C" fn(sys::GDNativeTypePtr, sys::GDNativeVariantPtr),
+                        > = Lazy::new(|| unsafe {
+                            interface_fn!(get_variant_to_type_constructor)(sys::$variant_type)
+                                .unwrap()
+                        });
+                        let mut value = std::mem::MaybeUninit::<$t>::uninit();
+                        CONSTR(value.as_mut_ptr() as *mut _, v.as_ptr());
+                        value.assume_init()
+                    }
+                }
+            }
+        };
+    }
+
+    impl_variant_conversions!(Vector2, GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VECTOR2);
+    impl_variant_conversions!(Vector3, GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VECTOR3);
+    impl_variant_conversions!(Vector2i, GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VECTOR2I);
+    impl_variant_conversions!(Vector3i, GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VECTOR3I);
+    impl_variant_conversions!(Rect2, GDNativeVariantType_GDNATIVE_VARIANT_TYPE_RECT2);
+    impl_variant_conversions!(Rect2i, GDNativeVariantType_GDNATIVE_VARIANT_TYPE_RECT2I);
+    impl_variant_conversions!(Plane, GDNativeVariantType_GDNATIVE_VARIANT_TYPE_PLANE);
+    impl_variant_conversions!(AABB, GDNativeVariantType_GDNATIVE_VARIANT_TYPE_AABB);
+    impl_variant_conversions!(
+        Quaternion,
+        GDNativeVariantType_GDNATIVE_VARIANT_TYPE_QUATERNION
+    );
+    impl_variant_conversions!(Basis, GDNativeVariantType_GDNATIVE_VARIANT_TYPE_BASIS);
+    impl_variant_conversions!(
+        Transform2D,
+        GDNativeVariantType_GDNATIVE_VARIANT_TYPE_TRANSFORM2D
+    );
+    impl_variant_conversions!(
+        Transform3D,
+        GDNativeVariantType_GDNATIVE_VARIANT_TYPE_TRANSFORM3D
+    );
+
+    impl_variant_conversions!(RID, GDNativeVariantType_GDNATIVE_VARIANT_TYPE_RID);
+
+    impl<T> From<Rid<T>> for Variant {
+        fn from(rid: Rid<T>) -> Self {
+            Variant::from(rid.untyped())
+        }
+    }
+
+    impl<T> From<&Variant> for Rid<T> {
+        fn from(v: &Variant) -> Self {
+            RID::from(v).typed()
         }
     }
 
@@ -283,4 +505,55 @@ mod conversions {
             }
         }
     }
+
+    /// Conversions for types that own engine memory, so that the target of a conversion out of a
+    /// `Variant` has to be a valid instance rather than uninitialized memory.
+    macro_rules! impl_variant_conversions_opaque {
+        ($t:ty, $variant_type:ident) => {
+            impl From<&$t> for Variant {
+                fn from(value: &$t) -> Self {
+                    unsafe {
+                        static CONSTR: Lazy<
+                            unsafe extern "C" fn(sys::GDNativeVariantPtr, sys::GDNativeTypePtr),
+                        > = Lazy::new(|| unsafe {
+                            interface_fn!(get_variant_from_type_constructor)(sys::$variant_type)
+                                .unwrap()
+                        });
+                        let mut v = Variant::uninit();
+                        CONSTR(v.as_mut_ptr(), value.as_ptr());
+                        v
+                    }
+                }
+            }
+
+            impl From<$t> for Variant {
+                fn from(value: $t) -> Self {
+                    Variant::from(&value)
+                }
+            }
+
+            impl From<&Variant> for $t {
+                fn from(v: &Variant) -> Self {
+                    unsafe {
+                        static CONSTR: Lazy<
+                            unsafe extern "C" fn(sys::GDNativeTypePtr, sys::GDNativeVariantPtr),
+                        > = Lazy::new(|| unsafe {
+                            interface_fn!(get_variant_to_type_constructor)(sys::$variant_type)
+                                .unwrap()
+                        });
+                        let mut value = <$t>::new();
+                        CONSTR(value.as_mut_ptr(), v.as_ptr());
+                        value
+                    }
+                }
+            }
+        };
+    }
+
+    impl_variant_conversions_opaque!(
+        StringName,
+        GDNativeVariantType_GDNATIVE_VARIANT_TYPE_STRING_NAME
+    );
+    impl_variant_conversions_opaque!(Callable, GDNativeVariantType_GDNATIVE_VARIANT_TYPE_CALLABLE);
+    impl_variant_conversions_opaque!(Signal, GDNativeVariantType_GDNATIVE_VARIANT_TYPE_SIGNAL);
 }
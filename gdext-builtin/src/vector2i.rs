@@ -0,0 +1,13 @@
+use crate::PtrCallArg;
+
+pub type Vector2i = glam::IVec2;
+
+impl PtrCallArg for Vector2i {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        *(*arg as *const Vector2i)
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        *(arg as *mut Vector2i) = self;
+    }
+}
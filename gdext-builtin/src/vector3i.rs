@@ -0,0 +1,13 @@
+use crate::PtrCallArg;
+
+pub type Vector3i = glam::IVec3;
+
+impl PtrCallArg for Vector3i {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        *(*arg as *const Vector3i)
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        *(arg as *mut Vector3i) = self;
+    }
+}
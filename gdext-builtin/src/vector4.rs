@@ -0,0 +1,125 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use gdext_sys::real;
+
+use crate::PtrCallArg;
+
+#[cfg(not(feature = "real_is_double"))]
+pub(crate) type GlamVec4 = glam::f32::Vec4;
+#[cfg(feature = "real_is_double")]
+pub(crate) type GlamVec4 = glam::f64::DVec4;
+
+/// A 4D vector of `real` components.
+///
+/// glam's `Vec4` is SIMD-aligned and can't be passed to Godot directly, so this type mirrors
+/// Godot's plain `x, y, z, w` layout and converts to and from glam for anything beyond the basic
+/// arithmetic.
+///
+/// The `gdnative_interface.h` these bindings are generated from predates the `VECTOR4` variant
+/// type, so this only supports ptrcalls and has no [`Variant`](crate::variant::Variant)
+/// conversions yet.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[repr(C)]
+pub struct Vector4 {
+    pub x: real,
+    pub y: real,
+    pub z: real,
+    pub w: real,
+}
+
+impl Vector4 {
+    pub const ZERO: Self = Self::splat(0.0);
+    pub const ONE: Self = Self::splat(1.0);
+
+    pub const fn new(x: real, y: real, z: real, w: real) -> Self {
+        Self { x, y, z, w }
+    }
+
+    pub const fn splat(v: real) -> Self {
+        Self::new(v, v, v, v)
+    }
+
+    pub fn dot(self, other: Self) -> real {
+        GlamVec4::from(self).dot(other.into())
+    }
+
+    pub fn length(self) -> real {
+        GlamVec4::from(self).length()
+    }
+
+    pub fn length_squared(self) -> real {
+        GlamVec4::from(self).length_squared()
+    }
+
+    pub fn normalized(self) -> Self {
+        GlamVec4::from(self).normalize().into()
+    }
+
+    pub fn lerp(self, to: Self, weight: real) -> Self {
+        GlamVec4::from(self).lerp(to.into(), weight).into()
+    }
+}
+
+impl From<GlamVec4> for Vector4 {
+    fn from(v: GlamVec4) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector4> for GlamVec4 {
+    fn from(v: Vector4) -> Self {
+        GlamVec4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+macro_rules! impl_vector4_op {
+    ($trait:ident, $fn:ident) => {
+        impl $trait for Vector4 {
+            type Output = Vector4;
+
+            fn $fn(self, other: Vector4) -> Vector4 {
+                GlamVec4::from(self).$fn(GlamVec4::from(other)).into()
+            }
+        }
+
+        impl $trait<real> for Vector4 {
+            type Output = Vector4;
+
+            fn $fn(self, other: real) -> Vector4 {
+                GlamVec4::from(self).$fn(other).into()
+            }
+        }
+    };
+}
+
+impl_vector4_op!(Add, add);
+impl_vector4_op!(Sub, sub);
+impl_vector4_op!(Mul, mul);
+impl_vector4_op!(Div, div);
+
+impl Neg for Vector4 {
+    type Output = Vector4;
+
+    fn neg(self) -> Vector4 {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl PtrCallArg for Vector4 {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        *(*arg as *const Vector4)
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        *(arg as *mut Vector4) = self;
+    }
+}
+
+const _: () = {
+    use std::mem::{align_of, offset_of, size_of};
+
+    assert!(size_of::<Vector4>() == 4 * size_of::<real>());
+    assert!(align_of::<Vector4>() == align_of::<real>());
+    assert!(offset_of!(Vector4, x) == 0);
+    assert!(offset_of!(Vector4, w) == 3 * size_of::<real>());
+};
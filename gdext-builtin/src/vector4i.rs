@@ -0,0 +1,18 @@
+use crate::PtrCallArg;
+
+/// A 4D vector of `i32` components.
+///
+/// The `gdnative_interface.h` these bindings are generated from predates the `VECTOR4I` variant
+/// type, so this only supports ptrcalls and has no [`Variant`](crate::variant::Variant)
+/// conversions yet.
+pub type Vector4i = glam::IVec4;
+
+impl PtrCallArg for Vector4i {
+    unsafe fn from_ptr_call_arg(arg: *const gdext_sys::GDNativeTypePtr) -> Self {
+        *(*arg as *const Vector4i)
+    }
+
+    unsafe fn to_ptr_call_arg(self, arg: gdext_sys::GDNativeTypePtr) {
+        *(arg as *mut Vector4i) = self;
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt;
+
+use gdext_sys as sys;
+
+use crate::gdext_print_warning;
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct GodotVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl GodotVersion {
+    /// The version of the running engine.
+    ///
+    /// # Safety
+    ///
+    /// The interface must have been set with [`sys::set_interface`].
+    pub unsafe fn engine() -> Self {
+        let interface = sys::get_interface();
+        Self {
+            major: interface.version_major,
+            minor: interface.version_minor,
+            patch: interface.version_patch,
+        }
+    }
+
+    /// The version the bindings were generated for, see [`sys::GODOT_API_VERSION`]. Patch releases
+    /// don't change the interface, so the patch version is always 0.
+    pub fn bindings() -> Self {
+        let (major, minor) = sys::GODOT_API_VERSION;
+        Self {
+            major,
+            minor,
+            patch: 0,
+        }
+    }
+
+    /// Whether an engine of this version and bindings for `other` share the same interface.
+    pub fn is_compatible_with(self, other: GodotVersion) -> bool {
+        self.major == other.major && self.minor == other.minor
+    }
+}
+
+impl fmt::Display for GodotVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// What to do when the engine's version doesn't match the bindings', passed to `gdext_init!`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum VersionPolicy {
+    /// Print an error and don't initialize the extension.
+    #[default]
+    Refuse,
+    /// Print a warning and initialize the extension anyway. Calls to interface functions that
+    /// changed between the versions may misbehave or crash.
+    Warn,
+}
+
+/// The engine's version doesn't match the version the bindings were generated for.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct VersionMismatch {
+    pub engine: GodotVersion,
+    pub bindings: GodotVersion,
+}
+
+impl fmt::Display for VersionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the engine is Godot {}, but the extension was built for Godot {}.{}",
+            self.engine, self.bindings.major, self.bindings.minor
+        )
+    }
+}
+
+impl std::error::Error for VersionMismatch {}
+
+/// Compares the engine's version with the bindings', failing on a mismatch under
+/// [`VersionPolicy::Refuse`] and printing a warning under [`VersionPolicy::Warn`].
+///
+/// # Safety
+///
+/// The interface must have been set with [`sys::set_interface`].
+pub unsafe fn check_engine_version(policy: VersionPolicy) -> Result<(), VersionMismatch> {
+    check_version(GodotVersion::engine(), GodotVersion::bindings(), policy)
+}
+
+fn check_version(
+    engine: GodotVersion,
+    bindings: GodotVersion,
+    policy: VersionPolicy,
+) -> Result<(), VersionMismatch> {
+    if engine.is_compatible_with(bindings) {
+        return Ok(());
+    }
+
+    let mismatch = VersionMismatch { engine, bindings };
+    match policy {
+        VersionPolicy::Refuse => Err(mismatch),
+        VersionPolicy::Warn => {
+            gdext_print_warning!("{}; initializing anyway", mismatch);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn version(major: u32, minor: u32, patch: u32) -> GodotVersion {
+        GodotVersion {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    #[test]
+    fn patch_releases_are_compatible() {
+        assert!(version(4, 0, 2).is_compatible_with(version(4, 0, 0)));
+        assert!(version(4, 0, 0).is_compatible_with(version(4, 0, 0)));
+    }
+
+    #[test]
+    fn other_minor_or_major_versions_are_incompatible() {
+        assert!(!version(4, 1, 0).is_compatible_with(version(4, 0, 0)));
+        assert!(!version(4, 0, 0).is_compatible_with(version(4, 1, 0)));
+        assert!(!version(3, 0, 0).is_compatible_with(version(4, 0, 0)));
+        assert!(!version(5, 0, 0).is_compatible_with(version(4, 0, 0)));
+    }
+
+    #[test]
+    fn bindings_match_the_api_version() {
+        let (major, minor) = sys::GODOT_API_VERSION;
+        assert_eq!(GodotVersion::bindings(), version(major, minor, 0));
+    }
+
+    #[test]
+    fn both_policies_accept_compatible_versions() {
+        for policy in [VersionPolicy::Refuse, VersionPolicy::Warn] {
+            assert_eq!(
+                check_version(version(4, 0, 3), version(4, 0, 0), policy),
+                Ok(())
+            );
+        }
+    }
+
+    #[test]
+    fn refuse_rejects_a_mismatch() {
+        let engine = version(4, 1, 1);
+        let bindings = version(4, 0, 0);
+        let err = check_version(engine, bindings, VersionPolicy::Refuse).unwrap_err();
+        assert_eq!(err, VersionMismatch { engine, bindings });
+        assert_eq!(
+            err.to_string(),
+            "the engine is Godot 4.1.1, but the extension was built for Godot 4.0"
+        );
+    }
+
+    #[test]
+    fn warn_accepts_a_mismatch() {
+        assert_eq!(
+            check_version(version(4, 1, 0), version(4, 0, 0), VersionPolicy::Warn),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn refuse_is_the_default() {
+        assert_eq!(VersionPolicy::default(), VersionPolicy::Refuse);
+    }
+}
@@ -0,0 +1,222 @@
+//! Run-time borrow tracking for instances of extension classes.
+//!
+//! The engine only hands out raw pointers to instances, and a method can call back into the same
+//! object (through a signal or a script) while it's running. Instances are stored in a [`GdCell`],
+//! so that such a reentrant call is reported as an error instead of creating aliasing references.
+
+use std::{
+    cell::UnsafeCell,
+    fmt,
+    sync::atomic::{AtomicIsize, Ordering},
+};
+
+use gdext_builtin::gdext_print_error;
+
+/// Borrow state of a [`GdCell`] that is borrowed exclusively.
+const EXCLUSIVE: isize = -1;
+
+/// A cell handing out either any number of shared borrows or one exclusive borrow, like a
+/// `RefCell`.
+///
+/// Only the main thread is supported. The borrow state is atomic, so that a call arriving from
+/// another thread while the value is borrowed exclusively is reported rather than racing with it.
+/// But shared borrows on several threads at once would need `T: Sync`, which extension classes
+/// aren't required to be.
+pub struct GdCell<T> {
+    /// The number of shared borrows, or [`EXCLUSIVE`].
+    state: AtomicIsize,
+    value: UnsafeCell<T>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum BorrowError {
+    /// An exclusive borrow was requested while the value is borrowed.
+    AlreadyBorrowed,
+    /// A borrow was requested while the value is borrowed exclusively.
+    AlreadyMutablyBorrowed,
+}
+
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyBorrowed => {
+                write!(
+                    f,
+                    "instance is already borrowed, so it can't be borrowed mutably"
+                )
+            }
+            Self::AlreadyMutablyBorrowed => write!(f, "instance is already borrowed mutably"),
+        }
+    }
+}
+
+impl std::error::Error for BorrowError {}
+
+/// Releases a borrow when dropped, including while unwinding.
+struct Release<'a> {
+    state: &'a AtomicIsize,
+    exclusive: bool,
+}
+
+impl Drop for Release<'_> {
+    fn drop(&mut self) {
+        if self.exclusive {
+            self.state.store(0, Ordering::Release);
+        } else {
+            self.state.fetch_sub(1, Ordering::Release);
+        }
+    }
+}
+
+impl<T> GdCell<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            state: AtomicIsize::new(0),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Runs `f` with a shared borrow of the value.
+    pub fn map<R>(&self, f: impl FnOnce(&T) -> R) -> Result<R, BorrowError> {
+        let mut state = self.state.load(Ordering::Relaxed);
+        loop {
+            if state == EXCLUSIVE {
+                return Err(BorrowError::AlreadyMutablyBorrowed);
+            }
+            match self.state.compare_exchange_weak(
+                state,
+                state + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(current) => state = current,
+            }
+        }
+
+        let _release = Release {
+            state: &self.state,
+            exclusive: false,
+        };
+        Ok(f(unsafe { &*self.value.get() }))
+    }
+
+    /// Runs `f` with an exclusive borrow of the value.
+    pub fn map_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> Result<R, BorrowError> {
+        if let Err(state) =
+            self.state
+                .compare_exchange(0, EXCLUSIVE, Ordering::Acquire, Ordering::Relaxed)
+        {
+            return Err(if state == EXCLUSIVE {
+                BorrowError::AlreadyMutablyBorrowed
+            } else {
+                BorrowError::AlreadyBorrowed
+            });
+        }
+
+        let _release = Release {
+            state: &self.state,
+            exclusive: true,
+        };
+        Ok(f(unsafe { &mut *self.value.get() }))
+    }
+
+    pub fn is_borrowed(&self) -> bool {
+        self.state.load(Ordering::Acquire) != 0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+/// Flattens the result of borrowing an instance for a call inside
+/// [`unwind::guard`](crate::unwind::guard), reporting a borrow error with `context()` naming the
+/// method.
+#[doc(hidden)]
+pub fn report_borrow_error<R>(
+    context: impl FnOnce() -> String,
+    result: Option<Result<R, BorrowError>>,
+) -> Option<R> {
+    match result? {
+        Ok(value) => Some(value),
+        Err(e) => {
+            let context = context();
+            gdext_print_error!("reentrant call to {}: {}", context, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+
+    use super::*;
+
+    #[test]
+    fn shared_borrows_nest() {
+        let cell = GdCell::new(1);
+        let sum = cell.map(|a| cell.map(|b| a + b));
+        assert_eq!(sum, Ok(Ok(2)));
+        assert!(!cell.is_borrowed());
+    }
+
+    #[test]
+    fn exclusive_borrow_while_shared_fails() {
+        let cell = GdCell::new(1);
+        let result = cell.map(|_| {
+            assert!(cell.is_borrowed());
+            cell.map_mut(|value| *value += 1)
+        });
+        assert_eq!(result, Ok(Err(BorrowError::AlreadyBorrowed)));
+        assert_eq!(cell.into_inner(), 1);
+    }
+
+    #[test]
+    fn any_borrow_while_exclusive_fails() {
+        let cell = GdCell::new(1);
+        let result = cell.map_mut(|_| (cell.map(|_| ()), cell.map_mut(|_| ())));
+        assert_eq!(
+            result,
+            Ok((
+                Err(BorrowError::AlreadyMutablyBorrowed),
+                Err(BorrowError::AlreadyMutablyBorrowed)
+            ))
+        );
+        assert!(!cell.is_borrowed());
+    }
+
+    #[test]
+    fn borrows_are_released_after_panics() {
+        let cell = GdCell::new(1);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| cell.map(|_| panic!("shared"))));
+        assert!(result.is_err());
+        assert!(!cell.is_borrowed());
+
+        let result =
+            panic::catch_unwind(AssertUnwindSafe(|| cell.map_mut(|_| panic!("exclusive"))));
+        assert!(result.is_err());
+        assert!(!cell.is_borrowed());
+
+        assert_eq!(cell.map_mut(|value| *value += 1), Ok(()));
+        assert_eq!(cell.into_inner(), 2);
+    }
+
+    #[test]
+    fn reports_borrow_errors() {
+        assert_eq!(report_borrow_error(String::new, Some(Ok(1))), Some(1));
+        assert_eq!(
+            report_borrow_error(
+                String::new,
+                Some(Err::<(), _>(BorrowError::AlreadyBorrowed))
+            ),
+            None
+        );
+        assert_eq!(
+            report_borrow_error(String::new, None::<Result<(), _>>),
+            None
+        );
+    }
+}
@@ -0,0 +1,53 @@
+//! The interface functions used to register classes and attach instances, kept in one place as
+//! they are the ones most likely to change between Godot versions.
+//!
+//! The bindings are only for Godot 4.0 (see `gdext_sys::GODOT_API_VERSION`), so each function has
+//! a single body.
+
+use gdext_sys::{self as sys, interface_fn};
+
+/// # Safety
+///
+/// `class_name` and `parent_class_name` must be nul-terminated.
+pub(crate) unsafe fn register_extension_class(
+    class_name: &str,
+    parent_class_name: &str,
+    creation_info: &sys::GDNativeExtensionClassCreationInfo,
+) {
+    interface_fn!(classdb_register_extension_class)(
+        sys::get_library(),
+        class_name.as_ptr() as *const _,
+        parent_class_name.as_ptr() as *const _,
+        creation_info as *const _,
+    );
+}
+
+/// # Safety
+///
+/// `class_name` must be nul-terminated.
+pub(crate) unsafe fn unregister_extension_class(class_name: &str) {
+    interface_fn!(classdb_unregister_extension_class)(
+        sys::get_library(),
+        class_name.as_ptr() as *const _,
+    );
+}
+
+/// # Safety
+///
+/// `class_name` must be nul-terminated.
+pub(crate) unsafe fn construct_object(class_name: &str) -> sys::GDNativeObjectPtr {
+    sys::debug_assert_main_thread();
+    interface_fn!(classdb_construct_object)(class_name.as_ptr() as *const _)
+}
+
+/// # Safety
+///
+/// `obj` must be a live object, and `class_name` the nul-terminated name of its extension class.
+pub(crate) unsafe fn set_instance(
+    obj: sys::GDNativeObjectPtr,
+    class_name: &str,
+    instance: *mut std::ffi::c_void,
+) {
+    sys::debug_assert_main_thread();
+    interface_fn!(object_set_instance)(obj, class_name.as_ptr() as *const _, instance as *mut _);
+}
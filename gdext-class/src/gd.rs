@@ -0,0 +1,185 @@
+//! Typed handles to engine objects.
+//!
+//! Objects and the scene tree aren't thread-safe, so [`Gd`] is neither `Send` nor `Sync`. To hand
+//! an object to another thread, convert it into a [`ThreadSafeGd`] (or just its [`InstanceId`]),
+//! which only turns back into a [`Gd`] on the main thread.
+
+use std::{fmt, marker::PhantomData};
+
+use gdext_builtin::{
+    signal::Signal,
+    variant::{CallError, Variant},
+};
+use gdext_sys::{self as sys, interface_fn};
+
+use crate::{task::SignalFuture, GodotClass};
+
+/// The ID of an engine object, valid across threads and outliving the object: once the object
+/// is freed, the ID no longer resolves to it.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct InstanceId(u64);
+
+impl InstanceId {
+    pub fn from_u64(id: u64) -> Self {
+        Self(id)
+    }
+
+    pub fn to_u64(self) -> u64 {
+        self.0
+    }
+
+    /// # Safety
+    ///
+    /// `object` must point to a live engine object.
+    pub unsafe fn of(object: sys::GDNativeObjectPtr) -> Self {
+        Self(interface_fn!(object_get_instance_id)(object))
+    }
+
+    /// Returns the object with this ID, or null if it was freed. Must be called on the main
+    /// thread, as the object could be freed right after on another thread.
+    pub fn object_ptr(self) -> sys::GDNativeObjectPtr {
+        sys::debug_assert_main_thread();
+        unsafe { interface_fn!(object_get_instance_from_id)(self.0) }
+    }
+}
+
+impl fmt::Display for InstanceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A handle to an engine object of class `T`, usable on the main thread only.
+pub struct Gd<T: GodotClass> {
+    object: sys::GDNativeObjectPtr,
+    /// Taken while the object is known to be alive, to tell whether it still is.
+    id: InstanceId,
+    // The raw pointer above already keeps `Gd` from being `Send` and `Sync`.
+    _class: PhantomData<*const T>,
+}
+
+impl<T: GodotClass> Gd<T> {
+    /// # Safety
+    ///
+    /// `object` must point to a live engine object of class `T` or a class derived from it.
+    pub unsafe fn from_object_ptr(object: sys::GDNativeObjectPtr) -> Self {
+        sys::debug_assert_main_thread();
+        Self::from_parts(object, InstanceId::of(object))
+    }
+
+    fn from_parts(object: sys::GDNativeObjectPtr, id: InstanceId) -> Self {
+        Self {
+            object,
+            id,
+            _class: PhantomData,
+        }
+    }
+
+    /// Returns a handle to the object `instance` is attached to.
+    pub fn from_instance(instance: &T) -> Self {
+        unsafe { Self::from_object_ptr(instance.native_object_ptr()) }
+    }
+
+    pub fn object_ptr(&self) -> sys::GDNativeObjectPtr {
+        self.object
+    }
+
+    pub fn instance_id(&self) -> InstanceId {
+        self.id
+    }
+
+    /// Whether the object hasn't been freed yet.
+    pub fn is_instance_valid(&self) -> bool {
+        // Without dereferencing the object, which may be freed.
+        self.id.object_ptr() == self.object
+    }
+
+    /// Calls `method` on the object, through the engine.
+    pub fn call(&self, method: &str, args: &[Variant]) -> Result<Variant, CallError> {
+        sys::debug_assert_main_thread();
+        unsafe { Variant::from_object_ptr(self.object) }.call(method, args)
+    }
+
+    /// Returns the signal `name` of the object.
+    pub fn signal(&self, name: &str) -> Signal {
+        sys::debug_assert_main_thread();
+        unsafe { Signal::from_object_signal(self.object, name) }.unwrap_or_default()
+    }
+
+    /// Returns a future resolving the next time the signal `name` is emitted, see
+    /// [`SignalFuture`].
+    #[track_caller]
+    pub fn to_signal(&self, name: &str) -> SignalFuture {
+        SignalFuture::new(&self.signal(name))
+    }
+
+    /// Converts the handle into one that can be sent to other threads.
+    pub fn into_thread_safe(self) -> ThreadSafeGd<T> {
+        ThreadSafeGd::new(self)
+    }
+}
+
+impl<T: GodotClass> Clone for Gd<T> {
+    fn clone(&self) -> Self {
+        Self::from_parts(self.object, self.id)
+    }
+}
+
+impl<T: GodotClass> fmt::Debug for Gd<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Gd<{}>({:p})", T::class_name(), self.object)
+    }
+}
+
+/// A handle to an engine object of class `T` that can be sent to and shared between threads,
+/// turning back into a [`Gd`] on the main thread only.
+pub struct ThreadSafeGd<T: GodotClass> {
+    id: InstanceId,
+    _class: PhantomData<fn() -> T>,
+}
+
+impl<T: GodotClass> ThreadSafeGd<T> {
+    pub fn new(gd: Gd<T>) -> Self {
+        Self {
+            id: gd.instance_id(),
+            _class: PhantomData,
+        }
+    }
+
+    pub fn instance_id(&self) -> InstanceId {
+        self.id
+    }
+
+    /// Returns a handle to the object, or `None` if it was freed.
+    ///
+    /// # Panics
+    ///
+    /// If not called on the main thread.
+    #[track_caller]
+    pub fn get(&self) -> Option<Gd<T>> {
+        assert!(
+            sys::is_main_thread(),
+            "ThreadSafeGd::get called off the main thread"
+        );
+        let object = self.id.object_ptr();
+        if object.is_null() {
+            None
+        } else {
+            Some(Gd::from_parts(object, self.id))
+        }
+    }
+}
+
+impl<T: GodotClass> Clone for ThreadSafeGd<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GodotClass> Copy for ThreadSafeGd<T> {}
+
+impl<T: GodotClass> fmt::Debug for ThreadSafeGd<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ThreadSafeGd<{}>({})", T::class_name(), self.id)
+    }
+}
@@ -0,0 +1,271 @@
+//! Development mode carrying instances of extension classes over a reload of the library.
+//!
+//! Before a class is unregistered, each of its instances is detached from its object and
+//! dropped. Everything the new instances need is kept as metadata on engine objects, as they are
+//! about the only thing outliving the library: each object stores the values of the properties
+//! the class registers for storage, and what [`GodotExtensionClass::save_properties`] returns,
+//! while the `Engine` singleton stores the IDs of the objects. Once the new library has
+//! registered the class again, a fresh instance is constructed for each of these objects, given
+//! the saved properties and attached to the object.
+//!
+//! This relies on the engine keeping the objects alive while the library is reloaded, and not
+//! calling into them in the meantime, as their class isn't registered then.
+
+use std::{
+    ffi::c_void,
+    sync::{atomic::Ordering, Mutex, PoisonError},
+};
+
+use gdext_builtin::{
+    gdext_print_warning, string::GodotString, string_name::StringName, variant::Variant,
+};
+use gdext_sys::{self as sys, interface_fn};
+
+use crate::{
+    attach_instance,
+    cell::{self, GdCell},
+    compat,
+    registry::ClassData,
+    unwind, GodotExtensionClass, InstanceId,
+};
+
+const OBJECTS_META_PREFIX: &str = "_gdext_hot_reload_objects/";
+const EXPORTED_NAMES_META: &str = "_gdext_hot_reload_exported";
+const EXPORTED_META_PREFIX: &str = "_gdext_hot_reload_exported/";
+const SAVED_NAMES_META: &str = "_gdext_hot_reload_saved";
+const SAVED_META_PREFIX: &str = "_gdext_hot_reload_saved/";
+
+/// `PROPERTY_USAGE_STORAGE`: the property is saved with the object, e.g. in scenes.
+const PROPERTY_USAGE_STORAGE: i64 = 2;
+
+struct LiveInstance {
+    /// Nul-terminated, like [`ClassData::name`].
+    class_name: String,
+    object: usize,
+    instance: usize,
+    save_properties: unsafe fn(usize) -> Vec<(String, Variant)>,
+    free: unsafe fn(usize),
+}
+
+static LIVE_INSTANCES: Mutex<Vec<LiveInstance>> = Mutex::new(Vec::new());
+
+pub(crate) fn track_instance<T: GodotExtensionClass>(
+    class: &ClassData,
+    object: sys::GDNativeObjectPtr,
+    instance: *mut GdCell<T>,
+) {
+    unsafe fn save_properties<T: GodotExtensionClass>(instance: usize) -> Vec<(String, Variant)> {
+        let instance = &*(instance as *const GdCell<T>);
+        let context = || format!("{}::save_properties", T::class_name());
+        let result = unwind::guard(context, || instance.map(T::save_properties));
+        cell::report_borrow_error(context, result).unwrap_or_default()
+    }
+    unsafe fn free<T: GodotExtensionClass>(instance: usize) {
+        drop(Box::from_raw(instance as *mut GdCell<T>));
+    }
+
+    LIVE_INSTANCES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(LiveInstance {
+            class_name: class.name.clone(),
+            object: object as usize,
+            instance: instance as usize,
+            save_properties: save_properties::<T>,
+            free: free::<T>,
+        });
+}
+
+pub(crate) fn untrack_instance(instance: *mut c_void) {
+    LIVE_INSTANCES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .retain(|live| live.instance != instance as usize);
+}
+
+/// Saves the properties of all instances of `class` onto their objects, then detaches and frees
+/// the instances, to be replaced by [`reattach_instances`] once the class is registered again.
+pub(crate) unsafe fn save_instances(class: &ClassData) {
+    let instances = {
+        let mut live_instances = LIVE_INSTANCES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let (matching, remaining) = live_instances
+            .drain(..)
+            .partition::<Vec<_>, _>(|live| live.class_name == class.name);
+        *live_instances = remaining;
+        matching
+    };
+    if instances.is_empty() {
+        return;
+    }
+
+    let exported = exported_properties(class);
+
+    let mut object_ids = Vec::with_capacity(instances.len());
+    for live in instances {
+        let object = Variant::from_object_ptr(live.object as sys::GDNativeObjectPtr);
+
+        let exported = exported
+            .iter()
+            .filter_map(|name| {
+                let value = object
+                    .call("get", &[Variant::from(StringName::from(name.as_str()))])
+                    .ok()?;
+                Some((name.clone(), value))
+            })
+            .collect();
+        store_properties(&object, EXPORTED_NAMES_META, EXPORTED_META_PREFIX, exported);
+
+        let saved = (live.save_properties)(live.instance);
+        store_properties(&object, SAVED_NAMES_META, SAVED_META_PREFIX, saved);
+
+        object_ids.push(InstanceId::of(live.object as _).to_string());
+
+        // The engine calls into the object's instance until it's replaced: detach it first, so
+        // that these calls find none rather than a freed one.
+        compat::set_instance(live.object as _, &class.name, std::ptr::null_mut());
+        (live.free)(live.instance);
+        class.live_instances.fetch_sub(1, Ordering::AcqRel);
+    }
+
+    if let Some(engine) = engine() {
+        set_meta(
+            &engine,
+            &objects_meta(class),
+            Variant::from(GodotString::from(object_ids.join(",").as_str())),
+        );
+    }
+}
+
+/// Attaches new instances to the objects whose instances were saved by [`save_instances`] before
+/// the library was reloaded.
+pub(crate) unsafe fn reattach_instances<T: GodotExtensionClass>(class: &ClassData) {
+    let Some(engine) = engine() else {
+        return;
+    };
+    let object_ids = take_meta(&engine, &objects_meta(class));
+    if object_ids.is_nil() {
+        return;
+    }
+    let object_ids = <GodotString as From<&Variant>>::from(&object_ids).to_string();
+
+    for id in object_ids.split(',').filter_map(|id| id.parse().ok()) {
+        let obj = InstanceId::from_u64(id).object_ptr();
+        if obj.is_null() {
+            gdext_print_warning!("object {} was freed while reloading the library", id);
+            continue;
+        }
+
+        let object = Variant::from_object_ptr(obj);
+        let exported = take_properties(&object, EXPORTED_NAMES_META, EXPORTED_META_PREFIX);
+        let saved = take_properties(&object, SAVED_NAMES_META, SAVED_META_PREFIX);
+
+        let mut instance = T::construct(obj);
+        instance.load_properties(saved);
+        attach_instance(obj, class, instance);
+
+        // Set through the engine, so that the class' setters run on the new instance.
+        for (name, value) in exported {
+            let _ = object.call(
+                "set",
+                &[Variant::from(StringName::from(name.as_str())), value],
+            );
+        }
+    }
+}
+
+/// Returns the names of the properties `class` itself registers for storage.
+fn exported_properties(class: &ClassData) -> Vec<String> {
+    let class_db = unsafe { interface_fn!(global_get_singleton)(c"ClassDB".as_ptr()) };
+    if class_db.is_null() {
+        return Vec::new();
+    }
+    let class_db = unsafe { Variant::from_object_ptr(class_db) };
+
+    let class_name = class.name.trim_end_matches('\0');
+    let Ok(properties) = class_db.call(
+        "class_get_property_list",
+        &[
+            Variant::from(StringName::from(class_name)),
+            Variant::from(true),
+        ],
+    ) else {
+        return Vec::new();
+    };
+
+    let name_key = Variant::from(GodotString::from("name"));
+    let usage_key = Variant::from(GodotString::from("usage"));
+    (0..)
+        .map_while(|i| properties.get_indexed(i))
+        .filter(|property| {
+            property
+                .get_keyed(&usage_key)
+                .is_some_and(|usage| i64::from(&usage) & PROPERTY_USAGE_STORAGE != 0)
+        })
+        .filter_map(|property| property.get_keyed(&name_key))
+        .map(|name| <GodotString as From<&Variant>>::from(&name).to_string())
+        .collect()
+}
+
+fn engine() -> Option<Variant> {
+    let engine = unsafe { interface_fn!(global_get_singleton)(c"Engine".as_ptr()) };
+    (!engine.is_null()).then(|| unsafe { Variant::from_object_ptr(engine) })
+}
+
+fn objects_meta(class: &ClassData) -> String {
+    format!("{OBJECTS_META_PREFIX}{}", class.name.trim_end_matches('\0'))
+}
+
+fn store_properties(
+    object: &Variant,
+    names_meta: &str,
+    prefix: &str,
+    properties: Vec<(String, Variant)>,
+) {
+    let names = properties
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>()
+        .join(",");
+    set_meta(
+        object,
+        names_meta,
+        Variant::from(GodotString::from(names.as_str())),
+    );
+    for (name, value) in properties {
+        set_meta(object, &format!("{prefix}{name}"), value);
+    }
+}
+
+fn take_properties(object: &Variant, names_meta: &str, prefix: &str) -> Vec<(String, Variant)> {
+    let names = <GodotString as From<&Variant>>::from(&take_meta(object, names_meta)).to_string();
+    names
+        .split(',')
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            let value = take_meta(object, &format!("{prefix}{name}"));
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+fn set_meta(object: &Variant, name: &str, value: Variant) {
+    let _ = object.call("set_meta", &[Variant::from(StringName::from(name)), value]);
+}
+
+fn take_meta(object: &Variant, name: &str) -> Variant {
+    let name = Variant::from(StringName::from(name));
+    let has_meta = object
+        .call("has_meta", std::slice::from_ref(&name))
+        .is_ok_and(|has_meta| bool::from(&has_meta));
+    if !has_meta {
+        return Variant::nil();
+    }
+
+    let value = object
+        .call("get_meta", std::slice::from_ref(&name))
+        .unwrap_or_else(|_| Variant::nil());
+    let _ = object.call("remove_meta", &[name]);
+    value
+}
@@ -0,0 +1,217 @@
+//! Extension classes the library registers for itself, to have objects the engine can call back
+//! into, e.g. from `call_deferred` or a signal.
+//!
+//! Their names are unique to each copy of the library, so that several extensions built with
+//! gdext (or one being reloaded) don't register the same class.
+
+use std::{ffi::CStr, marker::PhantomData};
+
+use gdext_builtin::variant::Variant;
+use gdext_sys::{self as sys, interface_fn};
+
+use crate::{
+    cell::GdCell, gd::InstanceId, GodotClass, GodotExtensionClass, GodotExtensionClassMethods,
+};
+
+/// A method of an internal class, called with the object and the call's arguments.
+pub(crate) type Handler = fn(sys::GDNativeObjectPtr, &[&Variant]) -> Variant;
+
+pub(crate) trait InternalClass: 'static {
+    /// The class name, before the suffix making it unique.
+    const PREFIX: &'static str;
+    /// The methods, all taking any number of arguments.
+    const METHODS: &'static [(&'static CStr, Handler)];
+}
+
+/// The engine class internal classes derive from.
+pub(crate) struct Object(sys::GDNativeObjectPtr);
+
+impl GodotClass for Object {
+    type Base = Object;
+
+    fn class_name() -> String {
+        "Object".to_string()
+    }
+
+    fn native_object_ptr(&self) -> sys::GDNativeObjectPtr {
+        self.0
+    }
+
+    fn upcast(&self) -> &Self::Base {
+        self
+    }
+
+    fn upcast_mut(&mut self) -> &mut Self::Base {
+        self
+    }
+}
+
+pub(crate) struct Internal<C> {
+    base: Object,
+    _class: PhantomData<C>,
+}
+
+impl<C: InternalClass> GodotClass for Internal<C> {
+    type Base = Object;
+
+    fn class_name() -> String {
+        static ANCHOR: u8 = 0;
+        format!("{}{:x}", C::PREFIX, &ANCHOR as *const u8 as usize)
+    }
+
+    fn upcast(&self) -> &Self::Base {
+        &self.base
+    }
+
+    fn upcast_mut(&mut self) -> &mut Self::Base {
+        &mut self.base
+    }
+}
+
+impl<C: InternalClass> GodotExtensionClass for Internal<C> {
+    fn construct(base: sys::GDNativeObjectPtr) -> Self {
+        Self {
+            base: Object(base),
+            _class: PhantomData,
+        }
+    }
+}
+
+impl<C: InternalClass> GodotExtensionClassMethods for Internal<C> {
+    fn virtual_call(_name: &str) -> sys::GDNativeExtensionClassCallVirtual {
+        None
+    }
+
+    /// Not `gdext_wrap_method!`, which registers methods under the name of the Rust type.
+    fn register_methods() {
+        let class_name = format!("{}\0", Self::class_name());
+        for method in C::METHODS {
+            unsafe { register_method::<C>(&class_name, method) };
+        }
+    }
+}
+
+/// Registers the class `C` with Godot.
+pub(crate) fn register<C: InternalClass>() {
+    crate::register_class::<Internal<C>>();
+}
+
+/// Creates an object of the class `C`, which must be registered.
+pub(crate) fn construct<C: InternalClass>() -> sys::GDNativeObjectPtr {
+    let class_name = format!("{}\0", Internal::<C>::class_name());
+    unsafe { crate::compat::construct_object(&class_name) }
+}
+
+/// Frees the object `id`, unless it's gone already.
+pub(crate) fn free(id: InstanceId) {
+    let object = id.object_ptr();
+    if !object.is_null() {
+        let _ = unsafe { Variant::from_object_ptr(object) }.call("free", &[]);
+    }
+}
+
+/// Returns the engine's main loop, usually the `SceneTree`, or `None` before it's created.
+pub(crate) fn main_loop() -> Option<Variant> {
+    let engine = unsafe { interface_fn!(global_get_singleton)(c"Engine".as_ptr()) };
+    if engine.is_null() {
+        return None;
+    }
+
+    let engine = unsafe { Variant::from_object_ptr(engine) };
+    engine
+        .call("get_main_loop", &[])
+        .ok()
+        .filter(|main_loop| !main_loop.is_nil())
+}
+
+/// # Safety
+///
+/// `class_name` must be nul-terminated.
+unsafe fn register_method<C: InternalClass>(
+    class_name: &str,
+    method: &'static (&'static CStr, Handler),
+) {
+    unsafe extern "C" fn call<C: InternalClass>(
+        method_data: *mut std::ffi::c_void,
+        instance: sys::GDExtensionClassInstancePtr,
+        args: *const sys::GDNativeVariantPtr,
+        arg_count: sys::GDNativeInt,
+        ret: sys::GDNativeVariantPtr,
+        err: *mut sys::GDNativeCallError,
+    ) {
+        let (name, handler) = *(method_data as *const (&'static CStr, Handler));
+        let instance = &*(instance as *const GdCell<Internal<C>>);
+        let args = if arg_count == 0 {
+            &[]
+        } else {
+            std::slice::from_raw_parts(args as *const &Variant, arg_count as usize)
+        };
+
+        let context = || format!("{}::{}", C::PREFIX, name.to_string_lossy());
+        let result = crate::unwind::guard(context, || {
+            instance.map(|instance| handler(instance.base.0, args))
+        });
+        (*err).error = match crate::cell::report_borrow_error(context, result) {
+            Some(value) => {
+                *(ret as *mut Variant) = value;
+                sys::GDNativeCallErrorType_GDNATIVE_CALL_OK
+            }
+            // Like failed calls of the classes' own methods.
+            None => sys::GDNativeCallErrorType_GDNATIVE_CALL_ERROR_INVALID_METHOD,
+        };
+    }
+
+    extern "C" fn get_type(
+        _method_data: *mut std::ffi::c_void,
+        _n: i32,
+    ) -> sys::GDNativeVariantType {
+        sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_NIL
+    }
+
+    unsafe extern "C" fn get_info(
+        _method_data: *mut std::ffi::c_void,
+        _n: i32,
+        ret: *mut sys::GDNativePropertyInfo,
+    ) {
+        *ret = sys::GDNativePropertyInfo {
+            type_: sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_NIL as _,
+            name: c"".as_ptr(),
+            class_name: std::ptr::null(),
+            hint: 0,
+            hint_string: std::ptr::null(),
+            usage: 7,
+        };
+    }
+
+    extern "C" fn get_meta(
+        _method_data: *mut std::ffi::c_void,
+        _n: i32,
+    ) -> sys::GDNativeExtensionClassMethodArgumentMetadata {
+        sys::GDNativeExtensionClassMethodArgumentMetadata_GDNATIVE_EXTENSION_METHOD_ARGUMENT_METADATA_NONE
+    }
+
+    let method_info = sys::GDNativeExtensionClassMethodInfo {
+        name: method.0.as_ptr(),
+        method_userdata: method as *const _ as *mut _,
+        call_func: Some(call::<C>),
+        // Vararg methods are always called through `call_func`.
+        ptrcall_func: None,
+        method_flags:
+            (sys::GDNativeExtensionClassMethodFlags_GDNATIVE_EXTENSION_METHOD_FLAGS_DEFAULT
+                | sys::GDNativeExtensionClassMethodFlags_GDNATIVE_EXTENSION_METHOD_FLAG_VARARG)
+                as _,
+        argument_count: 0,
+        has_return_value: 1,
+        get_argument_type_func: Some(get_type),
+        get_argument_info_func: Some(get_info),
+        get_argument_metadata_func: Some(get_meta),
+        default_argument_count: 0,
+        default_arguments: std::ptr::null_mut(),
+    };
+
+    interface_fn!(classdb_register_extension_class_method)(
+        sys::get_library() as *mut _,
+        class_name.as_ptr() as *const _,
+        &method_info,
+    );
+}
@@ -1,9 +1,33 @@
-use std::ffi::CStr;
+use std::{ffi::CStr, sync::atomic::Ordering};
 
+use cell::GdCell;
+use gdext_builtin::{gdext_print_error, variant::Variant};
 use gdext_sys::{self as sys, interface_fn};
+use registry::ClassData;
 
+pub mod cell;
+mod compat;
+pub mod gd;
+#[cfg(feature = "hot-reload")]
+mod hot_reload;
+mod internal;
 pub mod macros;
+pub mod main_thread;
+pub mod notification;
 pub mod property_info;
+pub mod registry;
+pub mod stats;
+pub mod task;
+pub mod unwind;
+
+#[doc(hidden)]
+pub use gdext_builtin;
+#[doc(hidden)]
+pub use inventory;
+
+pub use gd::{Gd, InstanceId, ThreadSafeGd};
+pub use gdext_macros::RegisterClass;
+pub use notification::Notification;
 
 pub trait GodotClass {
     type Base: GodotClass;
@@ -20,8 +44,30 @@ pub trait GodotClass {
 pub trait GodotExtensionClass: GodotClass {
     fn construct(base: sys::GDNativeObjectPtr) -> Self;
 
+    /// Called when a reference to a `RefCounted` object is added or removed. Calls made while one
+    /// of the instance's methods is running, e.g. when it passes itself to the engine, are
+    /// skipped, as the instance is borrowed then.
     fn reference(&mut self) {}
+    /// See [`reference`](Self::reference).
     fn unreference(&mut self) {}
+
+    /// Called for each notification the engine sends to the object, e.g.
+    /// [`Notification::EnterTree`] or [`Notification::Predelete`].
+    ///
+    /// Notifications sent while one of the instance's methods is running, e.g. by an `add_child`
+    /// or `free` call from that method, are skipped, as the instance is borrowed then.
+    fn on_notification(&mut self, _what: Notification) {}
+
+    /// Returns the state to carry over to the new instance when the library is hot-reloaded, on
+    /// top of the properties the class registers for storage, which are carried over
+    /// automatically. Only used with the `hot-reload` feature.
+    fn save_properties(&self) -> Vec<(String, Variant)> {
+        Vec::new()
+    }
+
+    /// Restores the properties returned by [`save_properties`](Self::save_properties) before the
+    /// library was reloaded, right after constructing the new instance.
+    fn load_properties(&mut self, _properties: Vec<(String, Variant)>) {}
 }
 
 pub trait GodotExtensionClassMethods {
@@ -35,14 +81,40 @@ pub fn register_class<T: GodotExtensionClass + GodotExtensionClassMethods>() {
         get_func: None,
         get_property_list_func: None,
         free_property_list_func: None,
-        notification_func: None,
+        notification_func: Some({
+            unsafe extern "C" fn notification<T: GodotExtensionClass>(
+                instance: sys::GDExtensionClassInstancePtr,
+                what: i32,
+            ) {
+                let Some(instance) = instance_ref::<T>(instance) else {
+                    return;
+                };
+                let context = || format!("{}::on_notification", T::class_name());
+                // While one of the instance's methods is running, it's borrowed and the
+                // notification is dropped, see `on_notification`.
+                let _ = unwind::guard(context, || {
+                    instance.map_mut(|instance| {
+                        let object = instance.native_object_ptr();
+                        let what = Notification::from_raw(what, |class| {
+                            notification::object_is_class(object, class)
+                        });
+                        instance.on_notification(what)
+                    })
+                });
+            }
+            notification::<T>
+        }),
         to_string_func: None,
         reference_func: Some({
             unsafe extern "C" fn reference<T: GodotExtensionClass>(
                 instance: *mut std::ffi::c_void,
             ) {
-                let instance = &mut *(instance as *mut T);
-                instance.reference();
+                let Some(instance) = instance_ref::<T>(instance) else {
+                    return;
+                };
+                let context = || format!("{}::reference", T::class_name());
+                // Skipped while the instance is borrowed, see `reference`.
+                let _ = unwind::guard(context, || instance.map_mut(T::reference));
             }
             reference::<T>
         }),
@@ -50,41 +122,37 @@ pub fn register_class<T: GodotExtensionClass + GodotExtensionClassMethods>() {
             unsafe extern "C" fn unreference<T: GodotExtensionClass>(
                 instance: *mut std::ffi::c_void,
             ) {
-                let instance = &mut *(instance as *mut T);
-                instance.unreference();
+                let Some(instance) = instance_ref::<T>(instance) else {
+                    return;
+                };
+                let context = || format!("{}::unreference", T::class_name());
+                // Skipped while the instance is borrowed, see `unreference`.
+                let _ = unwind::guard(context, || instance.map_mut(T::unreference));
             }
             unreference::<T>
         }),
         create_instance_func: Some({
             unsafe extern "C" fn instance<T: GodotExtensionClass>(
-                _class_userdata: *mut std::ffi::c_void,
+                class_userdata: *mut std::ffi::c_void,
             ) -> *mut std::ffi::c_void {
-                let class_name = format!("{}\0", T::class_name());
+                let class = &*(class_userdata as *const ClassData);
                 let parent_class_name = format!("{}\0", T::Base::class_name());
 
-                let obj =
-                    interface_fn!(classdb_construct_object)(parent_class_name.as_ptr() as *const _);
-                let instance = Box::new(T::construct(obj));
-                let instance_ptr = Box::into_raw(instance);
-
-                interface_fn!(object_set_instance)(
-                    obj,
-                    class_name.as_ptr() as *const _,
-                    instance_ptr as *mut _,
-                );
-
-                let binding_data_callbacks = sys::GDNativeInstanceBindingCallbacks {
-                    create_callback: None,
-                    free_callback: None,
-                    reference_callback: None,
-                };
-
-                interface_fn!(object_set_instance_binding)(
-                    obj,
-                    sys::get_library() as *mut _,
-                    instance_ptr as *mut _,
-                    &binding_data_callbacks,
-                );
+                let obj = compat::construct_object(&parent_class_name);
+                match unwind::guard(
+                    || format!("{}::construct", T::class_name()),
+                    || T::construct(obj),
+                ) {
+                    Some(instance) => {
+                        let instance = attach_instance(obj, class, instance);
+                        set_instance_binding(obj, instance);
+                    }
+                    None => {
+                        // Without an instance, the object is of the parent class.
+                        let parent = parent_class_name.trim_end_matches('\0');
+                        gdext_print_error!("constructed a bare `{}` instead", parent);
+                    }
+                }
 
                 obj
             }
@@ -92,10 +160,32 @@ pub fn register_class<T: GodotExtensionClass + GodotExtensionClassMethods>() {
         }),
         free_instance_func: Some({
             unsafe extern "C" fn free<T: GodotExtensionClass>(
-                _class_user_data: *mut std::ffi::c_void,
+                class_user_data: *mut std::ffi::c_void,
                 instance: *mut std::ffi::c_void,
             ) {
-                Box::from_raw(instance as *mut T);
+                if instance.is_null() {
+                    return;
+                }
+
+                #[cfg(feature = "hot-reload")]
+                hot_reload::untrack_instance(instance);
+
+                let instance = Box::from_raw(instance as *mut GdCell<T>);
+                if instance.is_borrowed() {
+                    // Freed by one of its own methods: dropping it would pull the instance out
+                    // from under that method.
+                    let class_name = T::class_name();
+                    gdext_print_error!(
+                        "instance of `{}` freed while one of its methods is running; leaking it",
+                        class_name
+                    );
+                    std::mem::forget(instance);
+                } else {
+                    unwind::guard(|| format!("{}::drop", T::class_name()), || drop(instance));
+                }
+
+                let class = &*(class_user_data as *const ClassData);
+                class.live_instances.fetch_sub(1, Ordering::AcqRel);
             }
             free::<T>
         }),
@@ -105,24 +195,68 @@ pub fn register_class<T: GodotExtensionClass + GodotExtensionClassMethods>() {
                 p_name: *const std::os::raw::c_char,
             ) -> sys::GDNativeExtensionClassCallVirtual {
                 let name = CStr::from_ptr(p_name);
-                T::virtual_call(name.to_str().unwrap())
+                unwind::guard(
+                    || format!("get_virtual({:?})", name),
+                    || T::virtual_call(name.to_str().unwrap()),
+                )
+                .flatten()
             }
             get_virtual::<T>
         }),
-        class_userdata: std::ptr::null_mut(),
+        class_userdata: registry::track_class(format!("{}\0", T::class_name())) as *mut _,
     };
 
     let class_name = format!("{}\0", T::class_name());
     let parent_class_name = format!("{}\0", T::Base::class_name());
 
     unsafe {
-        interface_fn!(classdb_register_extension_class)(
-            sys::get_library(),
-            class_name.as_ptr() as *const _,
-            parent_class_name.as_ptr() as *const _,
-            &creation_info as *const _,
-        );
+        compat::register_extension_class(&class_name, &parent_class_name, &creation_info);
     }
 
     T::register_methods();
+
+    #[cfg(feature = "hot-reload")]
+    unsafe {
+        hot_reload::reattach_instances::<T>(&*(creation_info.class_userdata as *const ClassData));
+    }
+}
+
+/// Attaches `instance` to the engine object `obj`, so that Godot calls into it.
+unsafe fn attach_instance<T: GodotExtensionClass>(
+    obj: sys::GDNativeObjectPtr,
+    class: &ClassData,
+    instance: T,
+) -> *mut GdCell<T> {
+    let instance_ptr = Box::into_raw(Box::new(GdCell::new(instance)));
+
+    compat::set_instance(obj, &class.name, instance_ptr as *mut _);
+
+    class.live_instances.fetch_add(1, Ordering::AcqRel);
+
+    #[cfg(feature = "hot-reload")]
+    hot_reload::track_instance(class, obj, instance_ptr);
+
+    instance_ptr
+}
+
+/// Registers `instance` as the binding of the newly created `obj`. Godot only accepts one binding
+/// per object, so instances attached later on keep the first one.
+unsafe fn set_instance_binding<T>(obj: sys::GDNativeObjectPtr, instance: *mut GdCell<T>) {
+    let binding_data_callbacks = sys::GDNativeInstanceBindingCallbacks {
+        create_callback: None,
+        free_callback: None,
+        reference_callback: None,
+    };
+
+    interface_fn!(object_set_instance_binding)(
+        obj,
+        sys::get_library() as *mut _,
+        instance as *mut _,
+        &binding_data_callbacks,
+    );
+}
+
+/// Returns the instance passed to a callback, or `None` if it has been detached from its object.
+unsafe fn instance_ref<'a, T>(instance: *mut std::ffi::c_void) -> Option<&'a GdCell<T>> {
+    (instance as *const GdCell<T>).as_ref()
 }
@@ -36,6 +36,7 @@ macro_rules! gdext_wrap_method_inner {
     ) => {
         unsafe {
             const NUM_ARGS: usize = gdext_wrap_method_parameter_count!($($pname,)*);
+            const METHOD: &str = concat!(stringify!($type_name), "::", stringify!($method_name));
 
             let method_info = sys::GDNativeExtensionClassMethodInfo {
                 name: concat!(stringify!($method_name), "\0").as_bytes().as_ptr() as *const _,
@@ -49,21 +50,30 @@ macro_rules! gdext_wrap_method_inner {
                         ret: sys::GDNativeVariantPtr,
                         err: *mut sys::GDNativeCallError,
                     ) {
-                        let instance = &mut *(instance as *mut $type_name);
+                        let instance = &*(instance as *const $crate::cell::GdCell<$type_name>);
 
-                        let mut idx = 0;
+                        let result = $crate::unwind::guard(
+                            || ::std::string::String::from(METHOD),
+                            || instance.$map_method(|instance| {
+                                let mut idx = 0;
 
-                        $(
-                            let $pname = <$pty as From<&Variant>>::from(&*(*args.offset(idx) as *mut Variant));
-                            idx += 1;
-                        )*
+                                $(
+                                    let $pname = <$pty as From<&Variant>>::from(&*(*args.offset(idx) as *mut Variant));
+                                    idx += 1;
+                                )*
 
-                        let ret_val = instance.$method_name($(
-                            $pname,
-                        )*);
-                        *(ret as *mut Variant) = Variant::from(ret_val);
+                                let ret_val = instance.$method_name($(
+                                    $pname,
+                                )*);
+                                *(ret as *mut Variant) = Variant::from(ret_val);
+                            }),
+                        );
+                        let result = $crate::cell::report_borrow_error(|| ::std::string::String::from(METHOD), result);
 
-                        (*err).error = sys::GDNativeCallErrorType_GDNATIVE_CALL_OK;
+                        (*err).error = match result {
+                            Some(()) => sys::GDNativeCallErrorType_GDNATIVE_CALL_OK,
+                            None => sys::GDNativeCallErrorType_GDNATIVE_CALL_ERROR_INVALID_METHOD,
+                        };
                     }
 
                     call
@@ -75,18 +85,25 @@ macro_rules! gdext_wrap_method_inner {
                         args: *const sys::GDNativeTypePtr,
                         ret: sys::GDNativeTypePtr,
                     ) {
-                        let instance = &mut *(instance as *mut $type_name);
-                        let mut idx = 0;
+                        let instance = &*(instance as *const $crate::cell::GdCell<$type_name>);
 
-                        $(
-                            let $pname = <$pty as gdext_builtin::PtrCallArg>::from_ptr_call_arg(args.offset(idx));
-                            idx += 1;
-                        )*
+                        let result = $crate::unwind::guard(
+                            || ::std::string::String::from(METHOD),
+                            || instance.$map_method(|instance| {
+                                let mut idx = 0;
 
-                        let ret_val = instance.$method_name($(
-                            $pname,
-                        )*);
-                        <$retty as gdext_builtin::PtrCallArg>::to_ptr_call_arg(ret_val, ret);
+                                $(
+                                    let $pname = <$pty as gdext_builtin::PtrCallArg>::from_ptr_call_arg(args.offset(idx));
+                                    idx += 1;
+                                )*
+
+                                let ret_val = instance.$method_name($(
+                                    $pname,
+                                )*);
+                                <$retty as gdext_builtin::PtrCallArg>::to_ptr_call_arg(ret_val, ret);
+                            }),
+                        );
+                        $crate::cell::report_borrow_error(|| ::std::string::String::from(METHOD), result);
                     }
 
                     call
@@ -100,14 +117,20 @@ macro_rules! gdext_wrap_method_inner {
                         _method_data: *mut std::ffi::c_void,
                         n: i32,
                     ) -> sys::GDNativeVariantType {
-                        // return value first
-                        let types: [gdext_sys::GDNativeVariantType; NUM_ARGS + 1] = [
-                            <$retty as $crate::property_info::PropertyInfoBuilder>::variant_type(),
-                            $(
-                                <$pty as $crate::property_info::PropertyInfoBuilder>::variant_type(),
-                            )*
-                        ];
-                        types[(n + 1) as usize]
+                        $crate::unwind::guard(
+                            || format!("{} argument type", METHOD),
+                            || {
+                                // return value first
+                                let types: [gdext_sys::GDNativeVariantType; NUM_ARGS + 1] = [
+                                    <$retty as $crate::property_info::PropertyInfoBuilder>::variant_type(),
+                                    $(
+                                        <$pty as $crate::property_info::PropertyInfoBuilder>::variant_type(),
+                                    )*
+                                ];
+                                types[(n + 1) as usize]
+                            },
+                        )
+                        .unwrap_or(sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_NIL)
                     }
                     get_type
                 }),
@@ -117,15 +140,20 @@ macro_rules! gdext_wrap_method_inner {
                         n: i32,
                         ret: *mut sys::GDNativePropertyInfo,
                     ) {
-                        // return value fist
-                        let infos: [gdext_sys::GDNativePropertyInfo; NUM_ARGS + 1] = [
-                            <$retty as $crate::property_info::PropertyInfoBuilder>::property_info(std::ffi::CStr::from_bytes_with_nul_unchecked("\0".as_bytes())),
-                            $(
-                                <$pty as $crate::property_info::PropertyInfoBuilder>::property_info(std::ffi::CStr::from_bytes_with_nul_unchecked(concat!(stringify!($pname), "\0").as_bytes())),
-                            )*
-                        ];
+                        $crate::unwind::guard(
+                            || format!("{} argument info", METHOD),
+                            || {
+                                // return value fist
+                                let infos: [gdext_sys::GDNativePropertyInfo; NUM_ARGS + 1] = [
+                                    <$retty as $crate::property_info::PropertyInfoBuilder>::property_info(std::ffi::CStr::from_bytes_with_nul_unchecked("\0".as_bytes())),
+                                    $(
+                                        <$pty as $crate::property_info::PropertyInfoBuilder>::property_info(std::ffi::CStr::from_bytes_with_nul_unchecked(concat!(stringify!($pname), "\0").as_bytes())),
+                                    )*
+                                ];
 
-                        *ret = infos[(n + 1) as usize];
+                                *ret = infos[(n + 1) as usize];
+                            },
+                        );
                     }
                     get_info
                 }),
@@ -134,14 +162,20 @@ macro_rules! gdext_wrap_method_inner {
                         _method_data: *mut std::ffi::c_void,
                         n: i32,
                     ) -> sys::GDNativeExtensionClassMethodArgumentMetadata {
-                        // return value first
-                        let metas: [gdext_sys::GDNativeExtensionClassMethodArgumentMetadata; NUM_ARGS + 1] = [
-                            <$retty as $crate::property_info::PropertyInfoBuilder>::metadata(),
-                            $(
-                                <$pty as $crate::property_info::PropertyInfoBuilder>::metadata(),
-                            )*
-                        ];
-                        metas[(n + 1) as usize]
+                        $crate::unwind::guard(
+                            || format!("{} argument metadata", METHOD),
+                            || {
+                                // return value first
+                                let metas: [gdext_sys::GDNativeExtensionClassMethodArgumentMetadata; NUM_ARGS + 1] = [
+                                    <$retty as $crate::property_info::PropertyInfoBuilder>::metadata(),
+                                    $(
+                                        <$pty as $crate::property_info::PropertyInfoBuilder>::metadata(),
+                                    )*
+                                ];
+                                metas[(n + 1) as usize]
+                            },
+                        )
+                        .unwrap_or(sys::GDNativeExtensionClassMethodArgumentMetadata_GDNATIVE_EXTENSION_METHOD_ARGUMENT_METADATA_NONE)
                     }
                     get_meta
                 }),
@@ -257,23 +291,32 @@ macro_rules! gdext_virtual_method_inner {
         ) -> $retty:ty
     ) => {
         Some({
+            const METHOD: &str = concat!(stringify!($type_name), "::", stringify!($method_name));
+
             unsafe extern "C" fn call(
                 instance: gdext_sys::GDExtensionClassInstancePtr,
                 args: *const gdext_sys::GDNativeTypePtr,
                 ret: gdext_sys::GDNativeTypePtr,
             ) {
-                let instance = &mut *(instance as *mut $type_name);
-                let mut idx = 0;
+                let instance = &*(instance as *const $crate::cell::GdCell<$type_name>);
 
-                $(
-                    let $pname = <$pty as gdext_builtin::PtrCallArg>::from_ptr_call_arg(args.offset(idx));
-                    idx += 1;
-                )*
+                let result = $crate::unwind::guard(
+                    || ::std::string::String::from(METHOD),
+                    || instance.$map_method(|instance| {
+                        let mut idx = 0;
+
+                        $(
+                            let $pname = <$pty as gdext_builtin::PtrCallArg>::from_ptr_call_arg(args.offset(idx));
+                            idx += 1;
+                        )*
 
-                let ret_val = instance.$method_name($(
-                    $pname,
-                )*);
-                <$retty as gdext_builtin::PtrCallArg>::to_ptr_call_arg(ret_val, ret);
+                        let ret_val = instance.$method_name($(
+                            $pname,
+                        )*);
+                        <$retty as gdext_builtin::PtrCallArg>::to_ptr_call_arg(ret_val, ret);
+                    }),
+                );
+                $crate::cell::report_borrow_error(|| ::std::string::String::from(METHOD), result);
             }
             call
         })
@@ -353,3 +396,101 @@ macro_rules! gdext_virtual_method_body {
         )
     };
 }
+
+/// Defines the entry point of the extension, exported under the symbol `$name`.
+///
+/// `$name` must match the `entry_symbol` in the `.gdextension` file. Each extension loaded into a
+/// Godot project needs its own entry symbol, so pick a name specific to the crate, e.g.
+/// `my_extension_init`.
+///
+/// `$f` is called with the [`InitOptions`](gdext_builtin::InitOptions) once Godot has loaded the
+/// library. Classes deriving `RegisterClass` are registered before any callback registered there
+/// runs at the same level.
+///
+/// Before that, the engine's version is checked against the bindings', and `$policy` decides what
+/// happens on a mismatch, [`VersionPolicy::Refuse`](gdext_builtin::version::VersionPolicy::Refuse)
+/// if omitted. Once refused, `$f` isn't called.
+#[macro_export]
+macro_rules! gdext_init {
+    ($name:ident, $f:expr) => {
+        $crate::gdext_init!(
+            $name,
+            $f,
+            $crate::gdext_builtin::version::VersionPolicy::Refuse
+        );
+    };
+    ($name:ident, $f:expr, $policy:expr) => {
+        #[no_mangle]
+        unsafe extern "C" fn $name(
+            interface: *const $crate::gdext_builtin::sys::GDNativeInterface,
+            library: $crate::gdext_builtin::sys::GDNativeExtensionClassLibraryPtr,
+            init: *mut $crate::gdext_builtin::sys::GDNativeInitialization,
+        ) {
+            // Declared inside the entry point, so that they neither clash with items of the
+            // surrounding module nor with another invocation of this macro.
+            unsafe extern "C" fn initialise(
+                _userdata: *mut ::std::ffi::c_void,
+                init_level: $crate::gdext_builtin::sys::GDNativeInitializationLevel,
+            ) {
+                $crate::unwind::guard(
+                    || ::std::string::String::from("initialization"),
+                    || {
+                        $crate::registry::initialise_level(
+                            $crate::gdext_builtin::InitLevel::from_sys(init_level),
+                        )
+                    },
+                );
+            }
+
+            unsafe extern "C" fn deinitialise(
+                _userdata: *mut ::std::ffi::c_void,
+                init_level: $crate::gdext_builtin::sys::GDNativeInitializationLevel,
+            ) {
+                $crate::unwind::guard(
+                    || ::std::string::String::from("deinitialization"),
+                    || {
+                        $crate::registry::deinitialise_level(
+                            $crate::gdext_builtin::InitLevel::from_sys(init_level),
+                        )
+                    },
+                );
+            }
+
+            $crate::gdext_builtin::sys::set_interface(interface);
+            $crate::gdext_builtin::sys::set_library(library);
+
+            let init_options = match $crate::gdext_builtin::version::check_engine_version($policy) {
+                ::std::result::Result::Ok(()) => $crate::unwind::guard(
+                    || ::std::string::String::from("the entry point"),
+                    || {
+                        let mut init_options = $crate::gdext_builtin::InitOptions::new();
+                        $crate::registry::register_classes_from_registry(&mut init_options);
+                        ($f)(&mut init_options);
+                        init_options
+                    },
+                ),
+                ::std::result::Result::Err(e) => {
+                    $crate::gdext_builtin::gdext_print_error!("Extension not initialized: {}", e);
+                    ::std::option::Option::None
+                }
+            };
+            // After a refused version or a panic, which have been reported already, nothing gets
+            // initialized.
+            let mut init_options = init_options.unwrap_or_default();
+
+            if let ::std::result::Result::Err(e) = init_options.validate() {
+                $crate::gdext_builtin::gdext_print_error!("Extension not initialized: {}", e);
+                init_options = $crate::gdext_builtin::InitOptions::new();
+            }
+
+            *init = $crate::gdext_builtin::sys::GDNativeInitialization {
+                minimum_initialization_level: init_options.lowest_init_level().to_sys(),
+                userdata: ::std::ptr::null_mut(),
+                initialize: ::std::option::Option::Some(initialise),
+                deinitialize: ::std::option::Option::Some(deinitialise),
+            };
+
+            $crate::gdext_builtin::INIT_OPTIONS = ::std::option::Option::Some(init_options);
+        }
+    };
+}
@@ -0,0 +1,261 @@
+//! Runs closures on the main thread, for threads handing results back to the scene tree.
+//!
+//! Closures are queued from any thread, and run by an internal object once the engine is idle,
+//! through `call_deferred`. The object is created when the `Scene` level is initialised, so
+//! closures queued before then wait for it, and those still queued when the level is
+//! deinitialised are dropped without running.
+
+use std::{
+    ffi::CStr,
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    task::{Context, Poll, Waker},
+};
+
+use gdext_builtin::{
+    callable::Callable, gdext_print_error, variant::Variant, InitLevel, InitOptions,
+};
+
+use crate::{
+    gd::InstanceId,
+    internal::{self, Handler, InternalClass},
+    unwind,
+};
+
+type Task = Box<dyn FnOnce() + Send>;
+
+static QUEUE: Mutex<Vec<Task>> = Mutex::new(Vec::new());
+
+/// Whether a call to the pump is already deferred, so that a burst of closures only defers one.
+static SCHEDULED: AtomicBool = AtomicBool::new(false);
+
+static PUMP: Mutex<Option<Pump>> = Mutex::new(None);
+
+/// The object running the queued closures.
+struct Pump {
+    id: InstanceId,
+    run: Callable,
+}
+
+/// Queues `f` to run on the main thread once the engine is idle, even when called from the main
+/// thread.
+///
+/// A panic in `f` is reported and handled like one in an engine callback.
+pub fn run_on_main_thread(f: impl FnOnce() + Send + 'static) {
+    QUEUE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(Box::new(f));
+    schedule();
+}
+
+/// Like [`run_on_main_thread`], returning a future that resolves to the result of `f`.
+pub fn run_on_main_thread_async<R, F>(f: F) -> MainThreadFuture<R>
+where
+    R: Send + 'static,
+    F: FnOnce() -> R + Send + 'static,
+{
+    let shared = Arc::new(Mutex::new(Shared {
+        result: None,
+        waker: None,
+        finished: false,
+    }));
+
+    let completion = Completion(shared.clone());
+    run_on_main_thread(move || completion.complete(f()));
+
+    MainThreadFuture { shared }
+}
+
+/// Returns a sender passing values to `handler` on the main thread.
+pub fn main_thread_channel<T, F>(handler: F) -> MainThreadSender<T>
+where
+    T: Send + 'static,
+    F: FnMut(T) + Send + 'static,
+{
+    MainThreadSender {
+        handler: Arc::new(Mutex::new(handler)),
+    }
+}
+
+/// Sends values to a handler running on the main thread, created by [`main_thread_channel`].
+pub struct MainThreadSender<T> {
+    handler: Arc<Mutex<dyn FnMut(T) + Send>>,
+}
+
+impl<T: Send + 'static> MainThreadSender<T> {
+    /// Queues a call of the handler with `value`, see [`run_on_main_thread`].
+    pub fn send(&self, value: T) {
+        let handler = self.handler.clone();
+        run_on_main_thread(move || {
+            let mut handler = handler.lock().unwrap_or_else(PoisonError::into_inner);
+            handler(value)
+        });
+    }
+}
+
+impl<T> Clone for MainThreadSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            handler: self.handler.clone(),
+        }
+    }
+}
+
+/// The closure passed to [`run_on_main_thread_async`] panicked, or was dropped without running
+/// as the library was unloaded first.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Canceled;
+
+impl std::fmt::Display for Canceled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "main thread closure panicked or was dropped without running"
+        )
+    }
+}
+
+impl std::error::Error for Canceled {}
+
+struct Shared<R> {
+    result: Option<R>,
+    waker: Option<Waker>,
+    /// Set once the closure ran or was dropped.
+    finished: bool,
+}
+
+/// The result of [`run_on_main_thread_async`].
+pub struct MainThreadFuture<R> {
+    shared: Arc<Mutex<Shared<R>>>,
+}
+
+impl<R> Future for MainThreadFuture<R> {
+    type Output = Result<R, Canceled>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.shared.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(result) = shared.result.take() {
+            Poll::Ready(Ok(result))
+        } else if shared.finished {
+            Poll::Ready(Err(Canceled))
+        } else {
+            shared.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// Resolves a [`MainThreadFuture`], as canceled if dropped before completing it.
+struct Completion<R>(Arc<Mutex<Shared<R>>>);
+
+impl<R> Completion<R> {
+    fn complete(&self, result: R) {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner).result = Some(result);
+    }
+}
+
+impl<R> Drop for Completion<R> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut shared = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+            shared.finished = true;
+            shared.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Defers a call to the pump, unless one is already deferred or there is no pump yet.
+fn schedule() {
+    if SCHEDULED.swap(true, Ordering::AcqRel) {
+        return;
+    }
+
+    let pump = PUMP.lock().unwrap_or_else(PoisonError::into_inner);
+    let scheduled = match &*pump {
+        Some(pump) => match pump.run.call_deferred(&[]) {
+            Ok(()) => true,
+            Err(e) => {
+                gdext_print_error!("failed to defer running main thread closures: {:?}", e);
+                false
+            }
+        },
+        None => false,
+    };
+    if !scheduled {
+        SCHEDULED.store(false, Ordering::Release);
+    }
+}
+
+/// Runs the queued closures, including those queued by them on the next idle frame.
+fn run_queued() {
+    SCHEDULED.store(false, Ordering::Release);
+
+    let tasks = mem::take(&mut *QUEUE.lock().unwrap_or_else(PoisonError::into_inner));
+    for task in tasks {
+        unwind::guard(|| "main thread closure".to_string(), task);
+    }
+}
+
+/// Registers the callbacks creating the pump when the `Scene` level is initialised and freeing
+/// it when that level is deinitialised, before its class is unregistered. Called by
+/// [`register_classes_from_registry`](crate::registry::register_classes_from_registry).
+pub(crate) fn register_pump(init: &mut InitOptions) {
+    init.register_init_function(InitLevel::Scene, start);
+    init.register_deinit_function(InitLevel::Scene, stop);
+}
+
+fn start() {
+    internal::register::<PumpClass>();
+
+    let object = internal::construct::<PumpClass>();
+    let id = unsafe { InstanceId::of(object) };
+    let run = match unsafe { Callable::from_object_method(object, "run") } {
+        Ok(run) => run,
+        Err(err) => {
+            gdext_print_error!("cannot create the main thread pump: {}", err);
+            internal::free(id);
+            return;
+        }
+    };
+    let pump = Pump { id, run };
+    *PUMP.lock().unwrap_or_else(PoisonError::into_inner) = Some(pump);
+
+    let pending = !QUEUE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .is_empty();
+    if pending {
+        schedule();
+    }
+}
+
+fn stop() {
+    let pump = PUMP.lock().unwrap_or_else(PoisonError::into_inner).take();
+    if let Some(pump) = pump {
+        internal::free(pump.id);
+    }
+
+    // Cancels the futures waiting on them.
+    let tasks = mem::take(&mut *QUEUE.lock().unwrap_or_else(PoisonError::into_inner));
+    drop(tasks);
+    SCHEDULED.store(false, Ordering::Release);
+}
+
+struct PumpClass;
+
+impl InternalClass for PumpClass {
+    const PREFIX: &'static str = "GdextMainThreadPump";
+    const METHODS: &'static [(&'static CStr, Handler)] = &[(c"run", |_, _| {
+        run_queued();
+        Variant::nil()
+    })];
+}
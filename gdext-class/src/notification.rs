@@ -0,0 +1,227 @@
+//! The notifications the engine sends to objects, passed to
+//! [`GodotExtensionClass::on_notification`](crate::GodotExtensionClass::on_notification).
+//!
+//! The values are copied by hand from the `NOTIFICATION_*` constants of Godot 4.0, as the tree has
+//! no `extension_api.json` for gdext-codegen to generate them from.
+
+use gdext_builtin::{string::GodotString, variant::Variant};
+use gdext_sys as sys;
+
+/// Declares [`Notification`] from the constants of each class. Classes reusing the values of
+/// others for their own notifications (`Control` and `Window` do) only get the variants of a
+/// group headed by a class name if they derive from that class; groups headed by `_` apply to all
+/// objects.
+macro_rules! notifications {
+    (
+        $(
+            $class:tt {
+                $( $(#[$attr:meta])* $variant:ident = $value:literal, )*
+            }
+        )*
+    ) => {
+        /// A notification sent to an object, named after Godot's `NOTIFICATION_*` constants.
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+        pub enum Notification {
+            $($( $(#[$attr])* $variant, )*)*
+            /// A notification without a variant, e.g. one specific to another class.
+            Unknown(i32),
+        }
+
+        impl Notification {
+            /// Converts the value `what` sent to an object, using `is_class` to tell whether the
+            /// object derives from a class.
+            pub fn from_raw(what: i32, is_class: impl Fn(&str) -> bool) -> Self {
+                match what {
+                    $($(
+                        $value if notifications!(@applies is_class, $class) => Self::$variant,
+                    )*)*
+                    what => Self::Unknown(what),
+                }
+            }
+
+            pub fn to_raw(self) -> i32 {
+                match self {
+                    $($( Self::$variant => $value, )*)*
+                    Self::Unknown(what) => what,
+                }
+            }
+        }
+    };
+    (@applies $is_class:ident, _) => { true };
+    (@applies $is_class:ident, $class:literal) => { $is_class($class) };
+}
+
+notifications! {
+    // Object
+    _ {
+        Postinitialize = 0,
+        Predelete = 1,
+    }
+    // Node, and Node's copies of MainLoop's
+    _ {
+        EnterTree = 10,
+        ExitTree = 11,
+        MovedInParent = 12,
+        Ready = 13,
+        Paused = 14,
+        Unpaused = 15,
+        PhysicsProcess = 16,
+        Process = 17,
+        Parented = 18,
+        Unparented = 19,
+        SceneInstantiated = 20,
+        DragBegin = 21,
+        DragEnd = 22,
+        PathRenamed = 23,
+        InternalProcess = 25,
+        InternalPhysicsProcess = 26,
+        PostEnterTree = 27,
+        Disabled = 28,
+        Enabled = 29,
+        WmMouseEnter = 1002,
+        WmMouseExit = 1003,
+        WmWindowFocusIn = 1004,
+        WmWindowFocusOut = 1005,
+        WmCloseRequest = 1006,
+        WmGoBackRequest = 1007,
+        WmSizeChanged = 1008,
+        WmDpiChange = 1009,
+        VpMouseEnter = 1010,
+        VpMouseExit = 1011,
+        OsMemoryWarning = 2009,
+        TranslationChanged = 2010,
+        WmAbout = 2011,
+        Crash = 2012,
+        OsImeUpdate = 2013,
+        ApplicationResumed = 2014,
+        ApplicationPaused = 2015,
+        ApplicationFocusIn = 2016,
+        ApplicationFocusOut = 2017,
+        TextServerChanged = 2018,
+        EditorPreSave = 9001,
+        EditorPostSave = 9002,
+    }
+    // CanvasItem and Node3D
+    _ {
+        TransformChanged = 2000,
+    }
+    "CanvasItem" {
+        Draw = 30,
+        /// `CanvasItem.NOTIFICATION_VISIBILITY_CHANGED`.
+        CanvasItemVisibilityChanged = 31,
+        EnterCanvas = 32,
+        ExitCanvas = 33,
+        /// `CanvasItem.NOTIFICATION_LOCAL_TRANSFORM_CHANGED`.
+        CanvasItemLocalTransformChanged = 35,
+        World2DChanged = 36,
+    }
+    "Node3D" {
+        EnterWorld = 41,
+        ExitWorld = 42,
+        /// `Node3D.NOTIFICATION_VISIBILITY_CHANGED`.
+        Node3DVisibilityChanged = 43,
+        /// `Node3D.NOTIFICATION_LOCAL_TRANSFORM_CHANGED`.
+        Node3DLocalTransformChanged = 44,
+    }
+}
+
+/// Whether `object` is of the class `class` or derives from it.
+pub(crate) fn object_is_class(object: sys::GDNativeObjectPtr, class: &str) -> bool {
+    unsafe { Variant::from_object_ptr(object) }
+        .call("is_class", &[Variant::from(GodotString::from(class))])
+        .is_ok_and(|ret| bool::from(&ret))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is(class: &'static str) -> impl Fn(&str) -> bool {
+        move |other| other == class
+    }
+
+    fn is_none(_class: &str) -> bool {
+        false
+    }
+
+    #[test]
+    fn converts_notifications_of_all_objects() {
+        assert_eq!(Notification::from_raw(1, is_none), Notification::Predelete);
+        assert_eq!(Notification::from_raw(13, is_none), Notification::Ready);
+        assert_eq!(
+            Notification::from_raw(1010, is_none),
+            Notification::VpMouseEnter
+        );
+        assert_eq!(
+            Notification::from_raw(9002, is_none),
+            Notification::EditorPostSave
+        );
+        assert_eq!(
+            Notification::from_raw(2000, is("Node3D")),
+            Notification::TransformChanged
+        );
+    }
+
+    #[test]
+    fn converts_class_notifications_only_for_that_class() {
+        assert_eq!(
+            Notification::from_raw(41, is("Node3D")),
+            Notification::EnterWorld
+        );
+        assert_eq!(
+            Notification::from_raw(41, is("CanvasItem")),
+            Notification::Unknown(41)
+        );
+        assert_eq!(
+            Notification::from_raw(41, is_none),
+            Notification::Unknown(41)
+        );
+
+        assert_eq!(
+            Notification::from_raw(31, is("CanvasItem")),
+            Notification::CanvasItemVisibilityChanged
+        );
+        assert_eq!(
+            Notification::from_raw(31, is("Node3D")),
+            Notification::Unknown(31)
+        );
+    }
+
+    #[test]
+    fn tells_apart_local_transform_changes() {
+        assert_eq!(
+            Notification::from_raw(35, is("CanvasItem")),
+            Notification::CanvasItemLocalTransformChanged
+        );
+        assert_eq!(
+            Notification::from_raw(44, is("Node3D")),
+            Notification::Node3DLocalTransformChanged
+        );
+        assert_eq!(
+            Notification::from_raw(44, is("CanvasItem")),
+            Notification::Unknown(44)
+        );
+        assert_eq!(Notification::CanvasItemLocalTransformChanged.to_raw(), 35);
+        assert_eq!(Notification::Node3DLocalTransformChanged.to_raw(), 44);
+        assert_eq!(Notification::World2DChanged.to_raw(), 36);
+    }
+
+    #[test]
+    fn keeps_unknown_values() {
+        assert_eq!(
+            Notification::from_raw(-1, is_none),
+            Notification::Unknown(-1)
+        );
+        assert_eq!(Notification::Unknown(12345).to_raw(), 12345);
+    }
+
+    #[test]
+    fn round_trips_all_values() {
+        for what in -1..10_000 {
+            for class in ["CanvasItem", "Node3D", "Control"] {
+                assert_eq!(Notification::from_raw(what, is(class)).to_raw(), what);
+            }
+            assert_eq!(Notification::from_raw(what, is_none).to_raw(), what);
+        }
+    }
+}
@@ -1,6 +1,24 @@
 use std::ffi::CStr;
 
-use gdext_builtin::{string::GodotString, vector2::Vector2, vector3::Vector3};
+use gdext_builtin::{
+    aabb::AABB,
+    basis::Basis,
+    callable::Callable,
+    plane::Plane,
+    quaternion::Quaternion,
+    rect2::Rect2,
+    rect2i::Rect2i,
+    rid::{Rid, RID},
+    signal::Signal,
+    string::GodotString,
+    string_name::StringName,
+    transform2d::Transform2D,
+    transform3d::Transform3D,
+    vector2::Vector2,
+    vector2i::Vector2i,
+    vector3::Vector3,
+    vector3i::Vector3i,
+};
 
 pub trait PropertyInfoBuilder {
     fn variant_type() -> gdext_sys::GDNativeVariantType;
@@ -46,6 +64,96 @@ impl PropertyInfoBuilder for Vector3 {
     }
 }
 
+impl PropertyInfoBuilder for Vector2i {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VECTOR2I
+    }
+}
+
+impl PropertyInfoBuilder for Vector3i {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VECTOR3I
+    }
+}
+
+impl PropertyInfoBuilder for Rect2 {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_RECT2
+    }
+}
+
+impl PropertyInfoBuilder for Rect2i {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_RECT2I
+    }
+}
+
+impl PropertyInfoBuilder for Plane {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_PLANE
+    }
+}
+
+impl PropertyInfoBuilder for AABB {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_AABB
+    }
+}
+
+impl PropertyInfoBuilder for Quaternion {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_QUATERNION
+    }
+}
+
+impl PropertyInfoBuilder for Basis {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_BASIS
+    }
+}
+
+impl PropertyInfoBuilder for Transform2D {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_TRANSFORM2D
+    }
+}
+
+impl PropertyInfoBuilder for Transform3D {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_TRANSFORM3D
+    }
+}
+
+impl PropertyInfoBuilder for RID {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_RID
+    }
+}
+
+impl<T> PropertyInfoBuilder for Rid<T> {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_RID
+    }
+}
+
+impl PropertyInfoBuilder for StringName {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_STRING_NAME
+    }
+}
+
+impl PropertyInfoBuilder for Callable {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_CALLABLE
+    }
+}
+
+impl PropertyInfoBuilder for Signal {
+    fn variant_type() -> gdext_sys::GDNativeVariantType {
+        gdext_sys::GDNativeVariantType_GDNATIVE_VARIANT_TYPE_SIGNAL
+    }
+}
+
 macro_rules! property_info_integer {
     ($type:ty, $meta:ident) => {
         impl PropertyInfoBuilder for $type {
@@ -0,0 +1,244 @@
+//! Bookkeeping of the classes registered with Godot, including those registered automatically
+//! through `#[derive(RegisterClass)]`.
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex, PoisonError,
+};
+
+use gdext_builtin::{gdext_print_error, InitLevel, InitOptions, INIT_OPTIONS};
+use gdext_sys as sys;
+
+use crate::{register_class, GodotClass, GodotExtensionClass, GodotExtensionClassMethods};
+
+/// An entry of the registry, submitted by `#[derive(RegisterClass)]`.
+#[doc(hidden)]
+pub struct ClassRegistration {
+    level: InitLevel,
+    class_name: fn() -> String,
+    parent_class_name: fn() -> String,
+    register: fn(),
+}
+
+impl ClassRegistration {
+    pub const fn new<T: GodotExtensionClass + GodotExtensionClassMethods>(
+        level: InitLevel,
+    ) -> Self {
+        Self {
+            level,
+            class_name: T::class_name,
+            parent_class_name: <T::Base as GodotClass>::class_name,
+            register: register_class::<T>,
+        }
+    }
+}
+
+inventory::collect!(ClassRegistration);
+
+/// Registers an init callback for every level at which classes from the registry need to be
+/// registered, along with those of [`main_thread`](crate::main_thread), [`task`](crate::task) and
+/// [`stats`](crate::stats).
+/// Called by `gdext_init!` before the user's closure, so that the classes are available to the
+/// callbacks registered there.
+#[doc(hidden)]
+pub fn register_classes_from_registry(init: &mut InitOptions) {
+    crate::main_thread::register_pump(init);
+    crate::task::register_executor(init);
+    crate::stats::register_monitors(init);
+
+    let mut levels = inventory::iter::<ClassRegistration>
+        .into_iter()
+        .map(|registration| registration.level)
+        .collect::<Vec<_>>();
+    levels.sort();
+    levels.dedup();
+
+    for level in levels {
+        init.register_init_function(level, move || register_level(level));
+    }
+}
+
+fn register_level(level: InitLevel) {
+    let all = inventory::iter::<ClassRegistration>
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let mut classes = all
+        .iter()
+        .filter(|registration| registration.level == level)
+        .map(|registration| (depth(registration, &all), registration))
+        .collect::<Vec<_>>();
+
+    // Godot needs the parent class to be registered before the child, so classes
+    // deriving from other extension classes go after them.
+    classes.sort_by_key(|(depth, _)| *depth);
+
+    for (_, registration) in classes {
+        (registration.register)();
+    }
+}
+
+/// Returns how many classes of the registry the class inherits from.
+fn depth(registration: &ClassRegistration, all: &[&ClassRegistration]) -> usize {
+    let mut depth = 0;
+    let mut current = registration;
+
+    while let Some(parent) = all
+        .iter()
+        .find(|r| (r.class_name)() == (current.parent_class_name)())
+    {
+        if parent.level > current.level {
+            let message = format!(
+                "class `{}` is registered at {:?}, before its parent class `{}` at {:?}",
+                (current.class_name)(),
+                current.level,
+                (parent.class_name)(),
+                parent.level,
+            );
+            gdext_print_error!("{}", message);
+        }
+
+        depth += 1;
+        current = parent;
+
+        // Engine classes are their own base, so are extension classes declared likewise.
+        if depth > all.len() {
+            break;
+        }
+    }
+    depth
+}
+
+/// A class registered with Godot, passed to the class callbacks as `class_userdata`.
+pub(crate) struct ClassData {
+    /// Nul-terminated, as passed to Godot.
+    pub(crate) name: String,
+    /// The level whose init callbacks registered the class, if any.
+    level: Option<InitLevel>,
+    pub(crate) live_instances: AtomicUsize,
+}
+
+/// Boxed so that the `class_userdata` pointers stay valid as the list grows.
+#[allow(clippy::vec_box)]
+static REGISTERED_CLASSES: Mutex<Vec<Box<ClassData>>> = Mutex::new(Vec::new());
+
+static CURRENT_INIT_LEVEL: Mutex<Option<InitLevel>> = Mutex::new(None);
+
+/// Records that the class `name` is about to be registered, and returns the pointer to pass to
+/// Godot as its `class_userdata`.
+pub(crate) fn track_class(name: String) -> *mut ClassData {
+    let level = *CURRENT_INIT_LEVEL
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    let mut data = Box::new(ClassData {
+        name,
+        level,
+        live_instances: AtomicUsize::new(0),
+    });
+    let ptr = &mut *data as *mut ClassData;
+
+    REGISTERED_CLASSES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(data);
+    ptr
+}
+
+/// Returns the name of each registered class, with its number of live instances.
+pub(crate) fn live_instances() -> Vec<(String, usize)> {
+    REGISTERED_CLASSES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .map(|class| {
+            (
+                class.name.trim_end_matches('\0').to_string(),
+                class.live_instances.load(Ordering::Acquire),
+            )
+        })
+        .collect()
+}
+
+/// Runs the init callbacks of `level`. Called by `gdext_init!`.
+///
+/// # Safety
+///
+/// Must only be called by Godot's `initialize` callback.
+#[doc(hidden)]
+pub unsafe fn initialise_level(level: InitLevel) {
+    *CURRENT_INIT_LEVEL
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some(level);
+
+    if let Some(init_options) = (*std::ptr::addr_of_mut!(INIT_OPTIONS)).as_mut() {
+        init_options.run_init_function(level);
+    }
+
+    *CURRENT_INIT_LEVEL
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Runs the deinit callbacks of `level`, then unregisters the classes registered at that level.
+/// Called by `gdext_init!`.
+///
+/// Once the lowest level has been deinitialised, all remaining classes are unregistered and all
+/// state kept by the extension is cleared, so that it can be loaded again.
+///
+/// # Safety
+///
+/// Must only be called by Godot's `deinitialize` callback.
+#[doc(hidden)]
+pub unsafe fn deinitialise_level(level: InitLevel) {
+    let lowest_level = match (*std::ptr::addr_of_mut!(INIT_OPTIONS)).as_mut() {
+        Some(init_options) => {
+            init_options.run_deinit_function(level);
+            init_options.lowest_init_level()
+        }
+        None => level,
+    };
+
+    unregister_classes(|class| class.level == Some(level));
+
+    if level <= lowest_level {
+        unregister_classes(|_| true);
+        crate::stats::report_leaks();
+
+        INIT_OPTIONS = None;
+        sys::lazy::reset_all();
+    }
+}
+
+/// Unregisters the matching classes, most recently registered first so that child classes go
+/// before their parents.
+unsafe fn unregister_classes(mut filter: impl FnMut(&ClassData) -> bool) {
+    let classes = {
+        let mut registered = REGISTERED_CLASSES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let (matching, remaining) = registered.drain(..).partition(|class| filter(class));
+        *registered = remaining;
+        matching
+    };
+
+    for class in classes.into_iter().rev() {
+        #[cfg(feature = "hot-reload")]
+        crate::hot_reload::save_instances(&class);
+
+        crate::compat::unregister_extension_class(&class.name);
+
+        let live_instances = class.live_instances.load(Ordering::Acquire);
+        if live_instances > 0 {
+            let message = format!(
+                "{} instance(s) of class `{}` still alive after unregistering it",
+                live_instances,
+                class.name.trim_end_matches('\0'),
+            );
+            gdext_print_error!("{}", message);
+
+            // The instances still point at the class data, and may be freed later on.
+            Box::leak(class);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Counts of live instances and engine values, to find leaks. They can also be shown in the
+//! editor debugger's Monitors tab, with [`add_performance_monitors`].
+//!
+//! When the extension is deinitialised, the values still alive are reported as warnings.
+
+use std::{
+    ffi::CStr,
+    sync::{Mutex, PoisonError},
+};
+
+use gdext_builtin::{
+    callable::Callable, gdext_print_warning, string::GodotString, string_name::StringName,
+    variant::Variant, InitLevel, InitOptions,
+};
+use gdext_sys::{self as sys, interface_fn};
+
+pub use gdext_builtin::stats::{live_strings, live_variants};
+
+use crate::{
+    gd::InstanceId,
+    internal::{self, Handler, InternalClass},
+    registry,
+};
+
+/// The number of live instances of each registered extension class, by class name.
+pub fn live_instances() -> Vec<(String, usize)> {
+    registry::live_instances()
+}
+
+struct Monitors {
+    /// The object whose `value` method the monitors call.
+    object: InstanceId,
+    ids: Vec<String>,
+}
+
+static MONITORS: Mutex<Option<Monitors>> = Mutex::new(None);
+
+/// Adds custom monitors to Godot's `Performance` singleton: the live instances of each class
+/// registered so far under `gdext_instances`, and in debug builds the live variants and strings
+/// under `gdext`. Calling it again adds the classes registered since.
+///
+/// Must be called on the main thread once the `Scene` level is initialised. The monitors are
+/// removed when that level is deinitialised.
+pub fn add_performance_monitors() {
+    sys::debug_assert_main_thread();
+
+    let performance = unsafe { interface_fn!(global_get_singleton)(c"Performance".as_ptr()) };
+    if performance.is_null() {
+        return;
+    }
+    let performance = unsafe { Variant::from_object_ptr(performance) };
+
+    let mut keys = live_instances()
+        .into_iter()
+        .map(|(class, _)| {
+            (
+                format!("gdext_instances/{}", class),
+                format!("class:{}", class),
+            )
+        })
+        .collect::<Vec<_>>();
+    if live_variants().is_some() {
+        keys.push(("gdext/live_variants".to_string(), "variants".to_string()));
+        keys.push(("gdext/live_strings".to_string(), "strings".to_string()));
+    }
+
+    let mut monitors = MONITORS.lock().unwrap_or_else(PoisonError::into_inner);
+    let monitors = monitors.get_or_insert_with(|| Monitors {
+        object: unsafe { InstanceId::of(internal::construct::<MonitorClass>()) },
+        ids: Vec::new(),
+    });
+
+    for (id, key) in keys {
+        if monitors.ids.contains(&id) {
+            continue;
+        }
+
+        let callable =
+            unsafe { Callable::from_object_method(monitors.object.object_ptr(), "value") };
+        let Ok(callable) = callable else {
+            continue;
+        };
+        let callable = callable.bind(&[Variant::from(GodotString::from(key.as_str()))]);
+        let added = performance.call(
+            "add_custom_monitor",
+            &[
+                Variant::from(StringName::from(id.as_str())),
+                Variant::from(&callable),
+            ],
+        );
+        if added.is_ok() {
+            monitors.ids.push(id);
+        }
+    }
+}
+
+/// Returns the value of the monitor `key`, bound when adding it.
+fn monitor_value(args: &[&Variant]) -> Variant {
+    let key = args
+        .last()
+        .map(|&key| <GodotString as From<&Variant>>::from(key).to_string())
+        .unwrap_or_default();
+
+    let value = match key.as_str() {
+        "variants" => live_variants().unwrap_or(0),
+        "strings" => live_strings().unwrap_or(0),
+        key => {
+            let class = key.strip_prefix("class:").unwrap_or(key);
+            live_instances()
+                .into_iter()
+                .find(|(name, _)| name == class)
+                .map_or(0, |(_, live)| live)
+        }
+    };
+    Variant::from(value as i64)
+}
+
+/// Prints the variants and strings still alive once all classes are unregistered, which are
+/// leaked. Leaked instances are reported as their class is unregistered.
+pub(crate) fn report_leaks() {
+    if let Some(variants) = live_variants().filter(|&live| live > 0) {
+        gdext_print_warning!(
+            "{} Variant(s) still alive after deinitialising the extension",
+            variants
+        );
+    }
+    if let Some(strings) = live_strings().filter(|&live| live > 0) {
+        gdext_print_warning!(
+            "{} GodotString(s) still alive after deinitialising the extension",
+            strings
+        );
+    }
+}
+
+/// Registers the callbacks registering the monitors' class when the `Scene` level is initialised,
+/// and removing the monitors when it's deinitialised. Called by
+/// [`register_classes_from_registry`](crate::registry::register_classes_from_registry).
+pub(crate) fn register_monitors(init: &mut InitOptions) {
+    init.register_init_function(InitLevel::Scene, internal::register::<MonitorClass>);
+    init.register_deinit_function(InitLevel::Scene, remove_monitors);
+}
+
+fn remove_monitors() {
+    let Some(monitors) = MONITORS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take()
+    else {
+        return;
+    };
+
+    let performance = unsafe { interface_fn!(global_get_singleton)(c"Performance".as_ptr()) };
+    if !performance.is_null() {
+        let performance = unsafe { Variant::from_object_ptr(performance) };
+        for id in &monitors.ids {
+            let id = Variant::from(StringName::from(id.as_str()));
+            let _ = performance.call("remove_custom_monitor", &[id]);
+        }
+    }
+    internal::free(monitors.object);
+}
+
+struct MonitorClass;
+
+impl InternalClass for MonitorClass {
+    const PREFIX: &'static str = "GdextStatsMonitor";
+    const METHODS: &'static [(&'static CStr, Handler)] =
+        &[(c"value", |_, args| monitor_value(args))];
+}
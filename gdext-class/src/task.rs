@@ -0,0 +1,560 @@
+//! Async tasks on the main thread, for sequencing logic over several frames.
+//!
+//! [`spawn`] runs a future right away until it first waits, and then again in every frame it was
+//! woken in, from the `SceneTree`'s `process_frame` signal. Futures can wait for a signal with
+//! [`SignalFuture`], for the next frame with [`next_frame`], or for some time with [`sleep`]:
+//!
+//! ```ignore
+//! task::spawn_for(&player, async move {
+//!     let _ = timer.to_signal("timeout").await;
+//!     task::sleep(0.5).await;
+//!     let _ = player.call("play", &[Variant::from(GodotString::from("jump"))]);
+//! });
+//! ```
+//!
+//! Futures can hold [`Gd`] handles, so tasks aren't `Send`: everything here must be used on the
+//! main thread. Wakers can be used from any thread though, e.g. by the future returned by
+//! [`run_on_main_thread_async`](crate::main_thread::run_on_main_thread_async).
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    ffi::CStr,
+    fmt,
+    future::Future,
+    marker::PhantomData,
+    mem,
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll, Wake, Waker},
+};
+
+use gdext_builtin::{
+    callable::Callable,
+    gdext_print_error,
+    signal::{Signal, SignalError},
+    variant::Variant,
+    InitLevel, InitOptions,
+};
+use gdext_sys as sys;
+
+use crate::{
+    gd::{Gd, InstanceId},
+    internal::{self, Handler, InternalClass},
+    unwind, GodotClass,
+};
+
+/// `Object.CONNECT_ONE_SHOT`.
+const CONNECT_ONE_SHOT: u32 = 4;
+
+type BoxedFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+thread_local! {
+    static STATE: RefCell<State> = RefCell::new(State::default());
+}
+
+/// Ids of the tasks woken since they were last polled, from any thread.
+static WOKEN: Mutex<Vec<u64>> = Mutex::new(Vec::new());
+
+#[derive(Default)]
+struct State {
+    /// The object whose `poll` method is connected to `process_frame`.
+    driver: Option<InstanceId>,
+    /// The scene tree `driver` is connected to.
+    tree: Option<InstanceId>,
+    /// Whether connecting to the scene tree is deferred until it exists.
+    retrying: bool,
+    next_id: u64,
+    tasks: HashMap<u64, Task>,
+    /// The pending signal futures, by the id of the object their signal is connected to.
+    signals: HashMap<InstanceId, SignalSlot>,
+    frame: u64,
+    frame_waiters: Vec<Waker>,
+}
+
+struct Task {
+    /// `None` while the task is being polled.
+    future: Option<BoxedFuture>,
+    owner: Option<InstanceId>,
+}
+
+struct SignalSlot {
+    emitter: InstanceId,
+    /// The arguments of the signal, once emitted.
+    args: Option<Vec<Variant>>,
+    waker: Option<Waker>,
+}
+
+struct TaskWaker(u64);
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        WOKEN
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(self.0);
+    }
+}
+
+/// Runs `future` on the main thread until it completes.
+///
+/// # Panics
+///
+/// If not called on the main thread.
+#[track_caller]
+pub fn spawn(future: impl Future<Output = ()> + 'static) -> TaskHandle {
+    assert_main_thread("spawn");
+    spawn_task(Box::pin(future), None)
+}
+
+/// Like [`spawn`], dropping the future without completing it once `owner` is freed.
+///
+/// # Panics
+///
+/// If not called on the main thread.
+#[track_caller]
+pub fn spawn_for<T: GodotClass>(
+    owner: &Gd<T>,
+    future: impl Future<Output = ()> + 'static,
+) -> TaskHandle {
+    assert_main_thread("spawn_for");
+    spawn_task(Box::pin(future), Some(owner.instance_id()))
+}
+
+fn spawn_task(future: BoxedFuture, owner: Option<InstanceId>) -> TaskHandle {
+    ensure_driven();
+
+    let id = STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let id = state.next_id;
+        state.next_id += 1;
+        state.tasks.insert(
+            id,
+            Task {
+                future: Some(future),
+                owner,
+            },
+        );
+        id
+    });
+    poll_task(id);
+
+    TaskHandle {
+        id,
+        _not_send: PhantomData,
+    }
+}
+
+/// A task started by [`spawn`], to be used on the main thread.
+#[derive(Copy, Clone, Debug)]
+pub struct TaskHandle {
+    id: u64,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl TaskHandle {
+    /// Drops the task's future without completing it. Does nothing if it's finished already.
+    pub fn cancel(self) {
+        let task = STATE.with(|state| state.borrow_mut().tasks.remove(&self.id));
+        drop(task);
+    }
+
+    /// Whether the task completed, panicked or was canceled.
+    pub fn is_finished(self) -> bool {
+        STATE.with(|state| !state.borrow().tasks.contains_key(&self.id))
+    }
+}
+
+/// Polls the task `id`, unless it's finished or being polled already.
+fn poll_task(id: u64) {
+    let taken = STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let task = state.tasks.get_mut(&id)?;
+        Some((task.future.take()?, task.owner))
+    });
+    let Some((mut future, owner)) = taken else {
+        return;
+    };
+
+    if owner.is_some_and(|owner| owner.object_ptr().is_null()) {
+        let task = STATE.with(|state| state.borrow_mut().tasks.remove(&id));
+        drop(task);
+        return;
+    }
+
+    let waker = Waker::from(Arc::new(TaskWaker(id)));
+    let mut cx = Context::from_waker(&waker);
+    let pending = unwind::guard(
+        || "async task".to_string(),
+        || future.as_mut().poll(&mut cx).is_pending(),
+    )
+    .unwrap_or(false);
+
+    // Put back, unless the task finished or was canceled while being polled.
+    let finished = STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        match state.tasks.get_mut(&id) {
+            Some(task) if pending => {
+                task.future = Some(future);
+                None
+            }
+            Some(_) => {
+                state.tasks.remove(&id);
+                Some(future)
+            }
+            None => Some(future),
+        }
+    });
+    drop(finished);
+}
+
+/// Connects the driver to the scene tree's `process_frame` signal, unless it's connected already.
+fn ensure_driven() {
+    let (driver, tree, retrying) = STATE.with(|state| {
+        let state = state.borrow();
+        (state.driver, state.tree, state.retrying)
+    });
+    let Some(driver) = driver else {
+        return;
+    };
+    if tree.is_some_and(|tree| !tree.object_ptr().is_null()) || retrying {
+        return;
+    }
+
+    let Some(main_loop) = internal::main_loop() else {
+        // Spawned while the extension is initialised: try again once the engine is running.
+        STATE.with(|state| state.borrow_mut().retrying = true);
+        crate::main_thread::run_on_main_thread(|| {
+            STATE.with(|state| state.borrow_mut().retrying = false);
+            ensure_driven();
+        });
+        return;
+    };
+
+    let tree = main_loop.to_object_ptr();
+    let connected = unsafe {
+        Signal::from_object_signal(tree, "process_frame")
+            .map_err(SignalError::from)
+            .and_then(|signal| {
+                signal.connect(
+                    &Callable::from_object_method(driver.object_ptr(), "poll")?,
+                    0,
+                )
+            })
+    };
+    if connected.is_ok() {
+        let tree = unsafe { InstanceId::of(tree) };
+        STATE.with(|state| state.borrow_mut().tree = Some(tree));
+    } else {
+        gdext_print_error!("async tasks need the main loop to be a `SceneTree`");
+    }
+}
+
+/// Polls the tasks woken since the last frame, called from `process_frame`.
+fn on_frame() {
+    let (wakers, orphaned) = STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        state.frame += 1;
+
+        let mut wakers = mem::take(&mut state.frame_waiters);
+        wakers.extend(
+            state
+                .signals
+                .values_mut()
+                .filter(|slot| slot.args.is_none() && slot.emitter.object_ptr().is_null())
+                .filter_map(|slot| slot.waker.take()),
+        );
+
+        let orphaned = state
+            .tasks
+            .iter()
+            .filter(|(_, task)| task.owner.is_some_and(|owner| owner.object_ptr().is_null()))
+            .map(|(&id, _)| id)
+            .collect::<Vec<_>>();
+        let orphaned = orphaned
+            .into_iter()
+            .filter_map(|id| state.tasks.remove(&id))
+            .collect::<Vec<_>>();
+
+        (wakers, orphaned)
+    });
+
+    drop(orphaned);
+    for waker in wakers {
+        waker.wake();
+    }
+
+    let woken = mem::take(&mut *WOKEN.lock().unwrap_or_else(PoisonError::into_inner));
+    let mut polled = HashSet::new();
+    for id in woken {
+        if polled.insert(id) {
+            poll_task(id);
+        }
+    }
+}
+
+/// Records the arguments of a signal a [`SignalFuture`] waits for, called by the signal.
+fn resolve_signal(receiver: sys::GDNativeObjectPtr, args: &[&Variant]) -> Variant {
+    let receiver = unsafe { InstanceId::of(receiver) };
+    let waker = STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let slot = state.signals.get_mut(&receiver)?;
+        slot.args = Some(args.iter().map(|&arg| arg.clone()).collect());
+        slot.waker.take()
+    });
+
+    if let Some(waker) = waker {
+        waker.wake();
+    }
+    Variant::nil()
+}
+
+/// The signal a [`SignalFuture`] waits for can't be emitted anymore, as its object was freed, or
+/// couldn't be connected to.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SignalCanceled;
+
+impl fmt::Display for SignalCanceled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "signal can't be emitted anymore")
+    }
+}
+
+impl std::error::Error for SignalCanceled {}
+
+/// A future resolving to the arguments of a signal the next time it's emitted.
+pub struct SignalFuture {
+    /// The object the signal is connected to, or `None` if it couldn't be connected.
+    receiver: Option<InstanceId>,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl SignalFuture {
+    /// Connects to `signal` right away, so that an emission before the future is first polled
+    /// isn't missed.
+    ///
+    /// # Panics
+    ///
+    /// If not called on the main thread.
+    #[track_caller]
+    pub fn new(signal: &Signal) -> Self {
+        assert_main_thread("SignalFuture::new");
+        Self {
+            receiver: connect_signal(signal),
+            _not_send: PhantomData,
+        }
+    }
+
+    fn release(&mut self) {
+        if let Some(receiver) = self.receiver.take() {
+            let slot = STATE.with(|state| state.borrow_mut().signals.remove(&receiver));
+            drop(slot);
+            internal::free(receiver);
+        }
+    }
+}
+
+/// Connects `signal` to a new receiver object, returning its id.
+fn connect_signal(signal: &Signal) -> Option<InstanceId> {
+    let started = STATE.with(|state| state.borrow().driver.is_some());
+    if !started || signal.is_null() {
+        return None;
+    }
+
+    let emitter = InstanceId::from_u64(signal.object_id());
+    let receiver = internal::construct::<SignalReceiver>();
+    let receiver_id = unsafe { InstanceId::of(receiver) };
+
+    STATE.with(|state| {
+        state.borrow_mut().signals.insert(
+            receiver_id,
+            SignalSlot {
+                emitter,
+                args: None,
+                waker: None,
+            },
+        )
+    });
+
+    let connected = unsafe { Callable::from_object_method(receiver, "resolve") }
+        .map_err(SignalError::from)
+        .and_then(|callable| signal.connect(&callable, CONNECT_ONE_SHOT));
+    if connected.is_ok() {
+        Some(receiver_id)
+    } else {
+        let slot = STATE.with(|state| state.borrow_mut().signals.remove(&receiver_id));
+        drop(slot);
+        internal::free(receiver_id);
+        None
+    }
+}
+
+impl Future for SignalFuture {
+    type Output = Result<Vec<Variant>, SignalCanceled>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Some(receiver) = self.receiver else {
+            return Poll::Ready(Err(SignalCanceled));
+        };
+
+        let ready = STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            let Some(slot) = state.signals.get_mut(&receiver) else {
+                // Dropped as the extension is deinitialised.
+                return Some(None);
+            };
+
+            if let Some(args) = slot.args.take() {
+                Some(Some(args))
+            } else if slot.emitter.object_ptr().is_null() {
+                Some(None)
+            } else {
+                slot.waker = Some(cx.waker().clone());
+                None
+            }
+        });
+
+        match ready {
+            Some(args) => {
+                self.release();
+                Poll::Ready(args.ok_or(SignalCanceled))
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl Drop for SignalFuture {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+/// Returns a future resolving in the next frame.
+pub fn next_frame() -> NextFrame {
+    NextFrame {
+        target: None,
+        _not_send: PhantomData,
+    }
+}
+
+/// The future returned by [`next_frame`].
+pub struct NextFrame {
+    /// The frame to resolve in, set when first polled.
+    target: Option<u64>,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Future for NextFrame {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        STATE.with(|state| {
+            let mut state = state.borrow_mut();
+            let frame = state.frame;
+            let target = *self.target.get_or_insert(frame + 1);
+
+            if frame >= target {
+                Poll::Ready(())
+            } else {
+                state.frame_waiters.push(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+    }
+}
+
+/// Returns a future resolving after `secs` seconds, using a `SceneTreeTimer`. Resolves right away
+/// if there is no scene tree.
+///
+/// # Panics
+///
+/// If not called on the main thread.
+#[track_caller]
+pub fn sleep(secs: f64) -> Sleep {
+    assert_main_thread("sleep");
+
+    let timer = internal::main_loop()
+        .and_then(|tree| tree.call("create_timer", &[Variant::from(secs)]).ok())
+        .filter(|timer| !timer.is_nil());
+    let signal = match timer {
+        // The scene tree keeps the timer until it times out.
+        Some(timer) => unsafe { Signal::from_object_signal(timer.to_object_ptr(), "timeout") }
+            .unwrap_or_default(),
+        None => {
+            gdext_print_error!("sleep needs the main loop to be a `SceneTree`");
+            Signal::new()
+        }
+    };
+
+    Sleep(SignalFuture::new(&signal))
+}
+
+/// The future returned by [`sleep`].
+pub struct Sleep(SignalFuture);
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        Pin::new(&mut self.0).poll(cx).map(|_| ())
+    }
+}
+
+#[track_caller]
+fn assert_main_thread(function: &str) {
+    assert!(
+        sys::is_main_thread(),
+        "{} called off the main thread",
+        function
+    );
+}
+
+/// Registers the callbacks creating the driver when the `Scene` level is initialised and
+/// dropping the tasks when it's deinitialised, before the internal classes are unregistered.
+/// Called by [`register_classes_from_registry`](crate::registry::register_classes_from_registry).
+pub(crate) fn register_executor(init: &mut InitOptions) {
+    init.register_init_function(InitLevel::Scene, start);
+    init.register_deinit_function(InitLevel::Scene, stop);
+}
+
+fn start() {
+    internal::register::<Driver>();
+    internal::register::<SignalReceiver>();
+
+    let driver = unsafe { InstanceId::of(internal::construct::<Driver>()) };
+    STATE.with(|state| state.borrow_mut().driver = Some(driver));
+}
+
+fn stop() {
+    let state = STATE.with(|state| mem::take(&mut *state.borrow_mut()));
+    WOKEN.lock().unwrap_or_else(PoisonError::into_inner).clear();
+
+    // Futures holding a `SignalFuture` free its receiver as they're dropped.
+    drop(state.tasks);
+    drop(state.frame_waiters);
+    for receiver in state.signals.into_keys() {
+        internal::free(receiver);
+    }
+    // Also disconnects it from the scene tree.
+    if let Some(driver) = state.driver {
+        internal::free(driver);
+    }
+}
+
+struct Driver;
+
+impl InternalClass for Driver {
+    const PREFIX: &'static str = "GdextTaskDriver";
+    const METHODS: &'static [(&'static CStr, Handler)] = &[(c"poll", |_, _| {
+        on_frame();
+        Variant::nil()
+    })];
+}
+
+struct SignalReceiver;
+
+impl InternalClass for SignalReceiver {
+    const PREFIX: &'static str = "GdextSignalReceiver";
+    const METHODS: &'static [(&'static CStr, Handler)] = &[(c"resolve", resolve_signal)];
+}
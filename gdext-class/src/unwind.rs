@@ -0,0 +1,114 @@
+//! Keeps panics from unwinding into the engine, which would abort it.
+//!
+//! Every callback the engine calls into runs inside [`guard`], which reports a panic through
+//! `print_error` and then acts according to the [`PanicPolicy`].
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Once,
+    },
+};
+
+use gdext_builtin::{gdext_print_error, variant::Variant};
+
+/// What to do after a panic in a callback has been reported.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum PanicPolicy {
+    /// Return from the callback as if it failed, and carry on.
+    #[default]
+    LogAndContinue,
+    /// Abort the process.
+    Abort,
+    /// Pause the scene tree, so that the game stops without losing the editor session.
+    PauseSceneTree,
+}
+
+static PANIC_POLICY: AtomicU8 = AtomicU8::new(PanicPolicy::LogAndContinue as u8);
+
+pub fn set_panic_policy(policy: PanicPolicy) {
+    PANIC_POLICY.store(policy as u8, Ordering::Relaxed);
+}
+
+pub fn panic_policy() -> PanicPolicy {
+    match PANIC_POLICY.load(Ordering::Relaxed) {
+        x if x == PanicPolicy::Abort as u8 => PanicPolicy::Abort,
+        x if x == PanicPolicy::PauseSceneTree as u8 => PanicPolicy::PauseSceneTree,
+        _ => PanicPolicy::LogAndContinue,
+    }
+}
+
+thread_local! {
+    /// Where the last panic on this thread happened, recorded by the panic hook.
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Chains a panic hook recording the panic's location, as the payload caught by `catch_unwind`
+/// doesn't have it.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let location = info
+                .location()
+                .map(|location| format!("{}:{}", location.file(), location.line()));
+            PANIC_LOCATION.with(|cell| *cell.borrow_mut() = location);
+            previous(info);
+        }));
+    });
+}
+
+/// Runs `f`, returning `None` if it panicked.
+///
+/// A panic is reported with the panic message, its location and `context()` (naming the
+/// callback), before applying the [`PanicPolicy`].
+#[doc(hidden)]
+pub fn guard<R>(context: impl FnOnce() -> String, f: impl FnOnce() -> R) -> Option<R> {
+    install_panic_hook();
+
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => Some(result),
+        Err(payload) => {
+            let location = PANIC_LOCATION
+                .with(|cell| cell.borrow_mut().take())
+                .unwrap_or_else(|| "<unknown location>".to_string());
+            let message = format!(
+                "panic in {} at {}: {}",
+                context(),
+                location,
+                payload_message(&*payload)
+            );
+            gdext_print_error!("{}", message);
+
+            match panic_policy() {
+                PanicPolicy::LogAndContinue => {}
+                PanicPolicy::Abort => std::process::abort(),
+                PanicPolicy::PauseSceneTree => pause_scene_tree(),
+            }
+            None
+        }
+    }
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "<non-string panic payload>"
+    }
+}
+
+fn pause_scene_tree() {
+    // Pausing is best effort: there's nothing left to report to if it fails too.
+    let _ = panic::catch_unwind(|| {
+        if let Some(tree) = crate::internal::main_loop() {
+            let _ = tree.call("set_pause", &[Variant::from(true)]);
+        }
+    });
+}
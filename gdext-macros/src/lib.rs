@@ -0,0 +1,64 @@
+//! Procedural macros for gdext-class; use them through the re-exports in that crate.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput, Ident, Token};
+
+const INIT_LEVELS: &[&str] = &["Core", "Servers", "Scene", "Driver", "Editor"];
+
+/// Registers the class with Godot once the extension is loaded, without having to call
+/// `register_class` in the `gdext_init!` closure.
+///
+/// Classes are registered at `InitLevel::Scene` unless another level is given with
+/// `#[register_class(level = Editor)]`.
+#[proc_macro_derive(RegisterClass, attributes(register_class))]
+pub fn derive_register_class(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match register_class(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn register_class(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "generic classes can't be registered automatically",
+        ));
+    }
+
+    let mut level = Ident::new("Scene", Span::call_site());
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("register_class"))
+    {
+        level = attr.parse_args_with(|input: syn::parse::ParseStream| {
+            let key: Ident = input.parse()?;
+            if key != "level" {
+                return Err(syn::Error::new_spanned(key, "expected `level`"));
+            }
+            input.parse::<Token![=]>()?;
+            input.parse::<Ident>()
+        })?;
+
+        if !INIT_LEVELS.iter().any(|l| level == l) {
+            return Err(syn::Error::new_spanned(
+                &level,
+                format!("expected one of {}", INIT_LEVELS.join(", ")),
+            ));
+        }
+    }
+
+    let name = &input.ident;
+    Ok(quote! {
+        ::gdext_class::inventory::submit! {
+            ::gdext_class::registry::ClassRegistration::new::<#name>(
+                ::gdext_class::gdext_builtin::InitLevel::#level
+            )
+        }
+    })
+}
@@ -0,0 +1,942 @@
+/* automatically generated by rust-bindgen 0.59.2 */
+
+pub type size_t = usize;
+pub type wchar_t = ::std::os::raw::c_ushort;
+pub type char16_t = u16;
+pub type char32_t = u32;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_NIL: GDNativeVariantType = 0;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_BOOL: GDNativeVariantType = 1;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_INT: GDNativeVariantType = 2;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_FLOAT: GDNativeVariantType = 3;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_STRING: GDNativeVariantType = 4;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VECTOR2: GDNativeVariantType = 5;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VECTOR2I: GDNativeVariantType = 6;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_RECT2: GDNativeVariantType = 7;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_RECT2I: GDNativeVariantType = 8;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VECTOR3: GDNativeVariantType = 9;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VECTOR3I: GDNativeVariantType = 10;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_TRANSFORM2D: GDNativeVariantType = 11;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_PLANE: GDNativeVariantType = 12;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_QUATERNION: GDNativeVariantType = 13;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_AABB: GDNativeVariantType = 14;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_BASIS: GDNativeVariantType = 15;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_TRANSFORM3D: GDNativeVariantType = 16;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_COLOR: GDNativeVariantType = 17;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_STRING_NAME: GDNativeVariantType = 18;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_NODE_PATH: GDNativeVariantType = 19;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_RID: GDNativeVariantType = 20;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_OBJECT: GDNativeVariantType = 21;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_CALLABLE: GDNativeVariantType = 22;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_SIGNAL: GDNativeVariantType = 23;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_DICTIONARY: GDNativeVariantType = 24;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_ARRAY: GDNativeVariantType = 25;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_PACKED_BYTE_ARRAY: GDNativeVariantType = 26;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_PACKED_INT32_ARRAY: GDNativeVariantType = 27;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_PACKED_INT64_ARRAY: GDNativeVariantType = 28;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_PACKED_FLOAT32_ARRAY: GDNativeVariantType = 29;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_PACKED_FLOAT64_ARRAY: GDNativeVariantType = 30;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_PACKED_STRING_ARRAY: GDNativeVariantType = 31;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_PACKED_VECTOR2_ARRAY: GDNativeVariantType = 32;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_PACKED_VECTOR3_ARRAY: GDNativeVariantType = 33;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_PACKED_COLOR_ARRAY: GDNativeVariantType = 34;
+pub const GDNativeVariantType_GDNATIVE_VARIANT_TYPE_VARIANT_MAX: GDNativeVariantType = 35;
+pub type GDNativeVariantType = ::std::os::raw::c_int;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_EQUAL: GDNativeVariantOperator = 0;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_NOT_EQUAL: GDNativeVariantOperator = 1;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_LESS: GDNativeVariantOperator = 2;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_LESS_EQUAL: GDNativeVariantOperator = 3;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_GREATER: GDNativeVariantOperator = 4;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_GREATER_EQUAL: GDNativeVariantOperator = 5;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_ADD: GDNativeVariantOperator = 6;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_SUBTRACT: GDNativeVariantOperator = 7;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_MULTIPLY: GDNativeVariantOperator = 8;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_DIVIDE: GDNativeVariantOperator = 9;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_NEGATE: GDNativeVariantOperator = 10;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_POSITIVE: GDNativeVariantOperator = 11;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_MODULE: GDNativeVariantOperator = 12;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_SHIFT_LEFT: GDNativeVariantOperator = 13;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_SHIFT_RIGHT: GDNativeVariantOperator = 14;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_BIT_AND: GDNativeVariantOperator = 15;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_BIT_OR: GDNativeVariantOperator = 16;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_BIT_XOR: GDNativeVariantOperator = 17;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_BIT_NEGATE: GDNativeVariantOperator = 18;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_AND: GDNativeVariantOperator = 19;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_OR: GDNativeVariantOperator = 20;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_XOR: GDNativeVariantOperator = 21;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_NOT: GDNativeVariantOperator = 22;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_IN: GDNativeVariantOperator = 23;
+pub const GDNativeVariantOperator_GDNATIVE_VARIANT_OP_MAX: GDNativeVariantOperator = 24;
+pub type GDNativeVariantOperator = ::std::os::raw::c_int;
+pub type GDNativeVariantPtr = *mut ::std::os::raw::c_void;
+pub type GDNativeStringNamePtr = *mut ::std::os::raw::c_void;
+pub type GDNativeStringPtr = *mut ::std::os::raw::c_void;
+pub type GDNativeObjectPtr = *mut ::std::os::raw::c_void;
+pub type GDNativeTypePtr = *mut ::std::os::raw::c_void;
+pub type GDNativeExtensionPtr = *mut ::std::os::raw::c_void;
+pub type GDNativeMethodBindPtr = *const ::std::os::raw::c_void;
+pub type GDNativeInt = i64;
+pub type GDNativeBool = u8;
+pub type GDObjectInstanceID = u64;
+pub const GDNativeCallErrorType_GDNATIVE_CALL_OK: GDNativeCallErrorType = 0;
+pub const GDNativeCallErrorType_GDNATIVE_CALL_ERROR_INVALID_METHOD: GDNativeCallErrorType = 1;
+pub const GDNativeCallErrorType_GDNATIVE_CALL_ERROR_INVALID_ARGUMENT: GDNativeCallErrorType = 2;
+pub const GDNativeCallErrorType_GDNATIVE_CALL_ERROR_TOO_MANY_ARGUMENTS: GDNativeCallErrorType = 3;
+pub const GDNativeCallErrorType_GDNATIVE_CALL_ERROR_TOO_FEW_ARGUMENTS: GDNativeCallErrorType = 4;
+pub const GDNativeCallErrorType_GDNATIVE_CALL_ERROR_INSTANCE_IS_NULL: GDNativeCallErrorType = 5;
+pub type GDNativeCallErrorType = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GDNativeCallError {
+    pub error: GDNativeCallErrorType,
+    pub argument: i32,
+    pub expected: i32,
+}
+pub type GDNativeVariantFromTypeConstructorFunc =
+    ::std::option::Option<unsafe extern "C" fn(arg1: GDNativeVariantPtr, arg2: GDNativeTypePtr)>;
+pub type GDNativeTypeFromVariantConstructorFunc =
+    ::std::option::Option<unsafe extern "C" fn(arg1: GDNativeTypePtr, arg2: GDNativeVariantPtr)>;
+pub type GDNativePtrOperatorEvaluator = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_left: GDNativeTypePtr,
+        p_right: GDNativeTypePtr,
+        r_result: GDNativeTypePtr,
+    ),
+>;
+pub type GDNativePtrBuiltInMethod = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_base: GDNativeTypePtr,
+        p_args: *const GDNativeTypePtr,
+        r_return: GDNativeTypePtr,
+        p_argument_count: ::std::os::raw::c_int,
+    ),
+>;
+pub type GDNativePtrConstructor = ::std::option::Option<
+    unsafe extern "C" fn(p_base: GDNativeTypePtr, p_args: *const GDNativeTypePtr),
+>;
+pub type GDNativePtrDestructor = ::std::option::Option<unsafe extern "C" fn(p_base: GDNativeTypePtr)>;
+pub type GDNativePtrSetter =
+    ::std::option::Option<unsafe extern "C" fn(p_base: GDNativeTypePtr, p_value: GDNativeTypePtr)>;
+pub type GDNativePtrGetter =
+    ::std::option::Option<unsafe extern "C" fn(p_base: GDNativeTypePtr, r_value: GDNativeTypePtr)>;
+pub type GDNativePtrIndexedSetter = ::std::option::Option<
+    unsafe extern "C" fn(p_base: GDNativeTypePtr, p_index: GDNativeInt, p_value: GDNativeTypePtr),
+>;
+pub type GDNativePtrIndexedGetter = ::std::option::Option<
+    unsafe extern "C" fn(p_base: GDNativeTypePtr, p_index: GDNativeInt, r_value: GDNativeTypePtr),
+>;
+pub type GDNativePtrKeyedSetter = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_base: GDNativeTypePtr,
+        p_key: GDNativeTypePtr,
+        p_value: GDNativeTypePtr,
+    ),
+>;
+pub type GDNativePtrKeyedGetter = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_base: GDNativeTypePtr,
+        p_key: GDNativeTypePtr,
+        r_value: GDNativeTypePtr,
+    ),
+>;
+pub type GDNativePtrKeyedChecker = ::std::option::Option<
+    unsafe extern "C" fn(p_base: GDNativeVariantPtr, p_key: GDNativeVariantPtr) -> u32,
+>;
+pub type GDNativePtrUtilityFunction = ::std::option::Option<
+    unsafe extern "C" fn(
+        r_return: GDNativeTypePtr,
+        p_arguments: *const GDNativeTypePtr,
+        p_argument_count: ::std::os::raw::c_int,
+    ),
+>;
+pub type GDNativeClassConstructor = ::std::option::Option<unsafe extern "C" fn() -> GDNativeObjectPtr>;
+pub type GDNativeInstanceBindingCreateCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_token: *mut ::std::os::raw::c_void,
+        p_instance: *mut ::std::os::raw::c_void,
+    ) -> *mut ::std::os::raw::c_void,
+>;
+pub type GDNativeInstanceBindingFreeCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_token: *mut ::std::os::raw::c_void,
+        p_instance: *mut ::std::os::raw::c_void,
+        p_binding: *mut ::std::os::raw::c_void,
+    ),
+>;
+pub type GDNativeInstanceBindingReferenceCallback = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_token: *mut ::std::os::raw::c_void,
+        p_binding: *mut ::std::os::raw::c_void,
+        p_reference: GDNativeBool,
+    ) -> GDNativeBool,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GDNativeInstanceBindingCallbacks {
+    pub create_callback: GDNativeInstanceBindingCreateCallback,
+    pub free_callback: GDNativeInstanceBindingFreeCallback,
+    pub reference_callback: GDNativeInstanceBindingReferenceCallback,
+}
+pub type GDExtensionClassInstancePtr = *mut ::std::os::raw::c_void;
+pub type GDNativeExtensionClassSet = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_instance: GDExtensionClassInstancePtr,
+        p_name: GDNativeStringNamePtr,
+        p_value: GDNativeVariantPtr,
+    ) -> GDNativeBool,
+>;
+pub type GDNativeExtensionClassGet = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_instance: GDExtensionClassInstancePtr,
+        p_name: GDNativeStringNamePtr,
+        r_ret: GDNativeVariantPtr,
+    ) -> GDNativeBool,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GDNativePropertyInfo {
+    pub type_: u32,
+    pub name: *const ::std::os::raw::c_char,
+    pub class_name: *const ::std::os::raw::c_char,
+    pub hint: u32,
+    pub hint_string: *const ::std::os::raw::c_char,
+    pub usage: u32,
+}
+pub type GDNativeExtensionClassGetPropertyList = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_instance: GDExtensionClassInstancePtr,
+        r_count: *mut u32,
+    ) -> *const GDNativePropertyInfo,
+>;
+pub type GDNativeExtensionClassFreePropertyList = ::std::option::Option<
+    unsafe extern "C" fn(p_instance: GDExtensionClassInstancePtr, p_list: *const GDNativePropertyInfo),
+>;
+pub type GDNativeExtensionClassNotification =
+    ::std::option::Option<unsafe extern "C" fn(p_instance: GDExtensionClassInstancePtr, p_what: i32)>;
+pub type GDNativeExtensionClassToString = ::std::option::Option<
+    unsafe extern "C" fn(p_instance: GDExtensionClassInstancePtr) -> *const ::std::os::raw::c_char,
+>;
+pub type GDNativeExtensionClassReference =
+    ::std::option::Option<unsafe extern "C" fn(p_instance: GDExtensionClassInstancePtr)>;
+pub type GDNativeExtensionClassUnreference =
+    ::std::option::Option<unsafe extern "C" fn(p_instance: GDExtensionClassInstancePtr)>;
+pub type GDNativeExtensionClassCallVirtual = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_instance: GDExtensionClassInstancePtr,
+        p_args: *const GDNativeTypePtr,
+        r_ret: GDNativeTypePtr,
+    ),
+>;
+pub type GDNativeExtensionClassCreateInstance = ::std::option::Option<
+    unsafe extern "C" fn(p_userdata: *mut ::std::os::raw::c_void) -> GDNativeObjectPtr,
+>;
+pub type GDNativeExtensionClassFreeInstance = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_userdata: *mut ::std::os::raw::c_void,
+        p_instance: GDExtensionClassInstancePtr,
+    ),
+>;
+pub type GDNativeExtensionClassObjectInstance = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_instance: GDExtensionClassInstancePtr,
+        p_object_instance: GDNativeObjectPtr,
+    ),
+>;
+pub type GDNativeExtensionClassGetVirtual = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_userdata: *mut ::std::os::raw::c_void,
+        p_name: *const ::std::os::raw::c_char,
+    ) -> GDNativeExtensionClassCallVirtual,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GDNativeExtensionClassCreationInfo {
+    pub set_func: GDNativeExtensionClassSet,
+    pub get_func: GDNativeExtensionClassGet,
+    pub get_property_list_func: GDNativeExtensionClassGetPropertyList,
+    pub free_property_list_func: GDNativeExtensionClassFreePropertyList,
+    pub notification_func: GDNativeExtensionClassNotification,
+    pub to_string_func: GDNativeExtensionClassToString,
+    pub reference_func: GDNativeExtensionClassReference,
+    pub unreference_func: GDNativeExtensionClassUnreference,
+    pub create_instance_func: GDNativeExtensionClassCreateInstance,
+    pub free_instance_func: GDNativeExtensionClassFreeInstance,
+    pub get_virtual_func: GDNativeExtensionClassGetVirtual,
+    pub class_userdata: *mut ::std::os::raw::c_void,
+}
+pub type GDNativeExtensionClassLibraryPtr = *mut ::std::os::raw::c_void;
+pub const GDNativeExtensionClassMethodFlags_GDNATIVE_EXTENSION_METHOD_FLAG_NORMAL:
+    GDNativeExtensionClassMethodFlags = 1;
+pub const GDNativeExtensionClassMethodFlags_GDNATIVE_EXTENSION_METHOD_FLAG_EDITOR:
+    GDNativeExtensionClassMethodFlags = 2;
+pub const GDNativeExtensionClassMethodFlags_GDNATIVE_EXTENSION_METHOD_FLAG_NOSCRIPT:
+    GDNativeExtensionClassMethodFlags = 4;
+pub const GDNativeExtensionClassMethodFlags_GDNATIVE_EXTENSION_METHOD_FLAG_CONST:
+    GDNativeExtensionClassMethodFlags = 8;
+pub const GDNativeExtensionClassMethodFlags_GDNATIVE_EXTENSION_METHOD_FLAG_REVERSE:
+    GDNativeExtensionClassMethodFlags = 16;
+pub const GDNativeExtensionClassMethodFlags_GDNATIVE_EXTENSION_METHOD_FLAG_VIRTUAL:
+    GDNativeExtensionClassMethodFlags = 32;
+pub const GDNativeExtensionClassMethodFlags_GDNATIVE_EXTENSION_METHOD_FLAG_FROM_SCRIPT:
+    GDNativeExtensionClassMethodFlags = 64;
+pub const GDNativeExtensionClassMethodFlags_GDNATIVE_EXTENSION_METHOD_FLAG_VARARG:
+    GDNativeExtensionClassMethodFlags = 128;
+pub const GDNativeExtensionClassMethodFlags_GDNATIVE_EXTENSION_METHOD_FLAG_STATIC:
+    GDNativeExtensionClassMethodFlags = 256;
+pub const GDNativeExtensionClassMethodFlags_GDNATIVE_EXTENSION_METHOD_FLAGS_DEFAULT:
+    GDNativeExtensionClassMethodFlags = 1;
+pub type GDNativeExtensionClassMethodFlags = ::std::os::raw::c_int;
+pub const GDNativeExtensionClassMethodArgumentMetadata_GDNATIVE_EXTENSION_METHOD_ARGUMENT_METADATA_NONE:
+    GDNativeExtensionClassMethodArgumentMetadata = 0;
+pub const GDNativeExtensionClassMethodArgumentMetadata_GDNATIVE_EXTENSION_METHOD_ARGUMENT_METADATA_INT_IS_INT8:
+    GDNativeExtensionClassMethodArgumentMetadata = 1;
+pub const GDNativeExtensionClassMethodArgumentMetadata_GDNATIVE_EXTENSION_METHOD_ARGUMENT_METADATA_INT_IS_INT16:
+    GDNativeExtensionClassMethodArgumentMetadata = 2;
+pub const GDNativeExtensionClassMethodArgumentMetadata_GDNATIVE_EXTENSION_METHOD_ARGUMENT_METADATA_INT_IS_INT32:
+    GDNativeExtensionClassMethodArgumentMetadata = 3;
+pub const GDNativeExtensionClassMethodArgumentMetadata_GDNATIVE_EXTENSION_METHOD_ARGUMENT_METADATA_INT_IS_INT64:
+    GDNativeExtensionClassMethodArgumentMetadata = 4;
+pub const GDNativeExtensionClassMethodArgumentMetadata_GDNATIVE_EXTENSION_METHOD_ARGUMENT_METADATA_INT_IS_UINT8:
+    GDNativeExtensionClassMethodArgumentMetadata = 5;
+pub const GDNativeExtensionClassMethodArgumentMetadata_GDNATIVE_EXTENSION_METHOD_ARGUMENT_METADATA_INT_IS_UINT16:
+    GDNativeExtensionClassMethodArgumentMetadata = 6;
+pub const GDNativeExtensionClassMethodArgumentMetadata_GDNATIVE_EXTENSION_METHOD_ARGUMENT_METADATA_INT_IS_UINT32:
+    GDNativeExtensionClassMethodArgumentMetadata = 7;
+pub const GDNativeExtensionClassMethodArgumentMetadata_GDNATIVE_EXTENSION_METHOD_ARGUMENT_METADATA_INT_IS_UINT64:
+    GDNativeExtensionClassMethodArgumentMetadata = 8;
+pub const GDNativeExtensionClassMethodArgumentMetadata_GDNATIVE_EXTENSION_METHOD_ARGUMENT_METADATA_REAL_IS_FLOAT:
+    GDNativeExtensionClassMethodArgumentMetadata = 9;
+pub const GDNativeExtensionClassMethodArgumentMetadata_GDNATIVE_EXTENSION_METHOD_ARGUMENT_METADATA_REAL_IS_DOUBLE:
+    GDNativeExtensionClassMethodArgumentMetadata = 10;
+pub type GDNativeExtensionClassMethodArgumentMetadata = ::std::os::raw::c_int;
+pub type GDNativeExtensionClassMethodCall = ::std::option::Option<
+    unsafe extern "C" fn(
+        method_userdata: *mut ::std::os::raw::c_void,
+        p_instance: GDExtensionClassInstancePtr,
+        p_args: *const GDNativeVariantPtr,
+        p_argument_count: GDNativeInt,
+        r_return: GDNativeVariantPtr,
+        r_error: *mut GDNativeCallError,
+    ),
+>;
+pub type GDNativeExtensionClassMethodPtrCall = ::std::option::Option<
+    unsafe extern "C" fn(
+        method_userdata: *mut ::std::os::raw::c_void,
+        p_instance: GDExtensionClassInstancePtr,
+        p_args: *const GDNativeTypePtr,
+        r_ret: GDNativeTypePtr,
+    ),
+>;
+pub type GDNativeExtensionClassMethodGetArgumentType = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_method_userdata: *mut ::std::os::raw::c_void,
+        p_argument: i32,
+    ) -> GDNativeVariantType,
+>;
+pub type GDNativeExtensionClassMethodGetArgumentInfo = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_method_userdata: *mut ::std::os::raw::c_void,
+        p_argument: i32,
+        r_info: *mut GDNativePropertyInfo,
+    ),
+>;
+pub type GDNativeExtensionClassMethodGetArgumentMetadata = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_method_userdata: *mut ::std::os::raw::c_void,
+        p_argument: i32,
+    ) -> GDNativeExtensionClassMethodArgumentMetadata,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GDNativeExtensionClassMethodInfo {
+    pub name: *const ::std::os::raw::c_char,
+    pub method_userdata: *mut ::std::os::raw::c_void,
+    pub call_func: GDNativeExtensionClassMethodCall,
+    pub ptrcall_func: GDNativeExtensionClassMethodPtrCall,
+    pub method_flags: u32,
+    pub argument_count: u32,
+    pub has_return_value: GDNativeBool,
+    pub get_argument_type_func: GDNativeExtensionClassMethodGetArgumentType,
+    pub get_argument_info_func: GDNativeExtensionClassMethodGetArgumentInfo,
+    pub get_argument_metadata_func: GDNativeExtensionClassMethodGetArgumentMetadata,
+    pub default_argument_count: u32,
+    pub default_arguments: *mut GDNativeVariantPtr,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GDNativeInterface {
+    pub version_major: u32,
+    pub version_minor: u32,
+    pub version_patch: u32,
+    pub version_string: *const ::std::os::raw::c_char,
+    pub mem_alloc:
+        ::std::option::Option<unsafe extern "C" fn(p_bytes: size_t) -> *mut ::std::os::raw::c_void>,
+    pub mem_realloc: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_ptr: *mut ::std::os::raw::c_void,
+            p_bytes: size_t,
+        ) -> *mut ::std::os::raw::c_void,
+    >,
+    pub mem_free: ::std::option::Option<unsafe extern "C" fn(p_ptr: *mut ::std::os::raw::c_void)>,
+    pub print_error: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_description: *const ::std::os::raw::c_char,
+            p_function: *const ::std::os::raw::c_char,
+            p_file: *const ::std::os::raw::c_char,
+            p_line: i32,
+        ),
+    >,
+    pub print_warning: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_description: *const ::std::os::raw::c_char,
+            p_function: *const ::std::os::raw::c_char,
+            p_file: *const ::std::os::raw::c_char,
+            p_line: i32,
+        ),
+    >,
+    pub print_script_error: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_description: *const ::std::os::raw::c_char,
+            p_function: *const ::std::os::raw::c_char,
+            p_file: *const ::std::os::raw::c_char,
+            p_line: i32,
+        ),
+    >,
+    pub variant_new_copy:
+        ::std::option::Option<unsafe extern "C" fn(r_dest: GDNativeVariantPtr, p_src: GDNativeVariantPtr)>,
+    pub variant_new_nil: ::std::option::Option<unsafe extern "C" fn(r_dest: GDNativeVariantPtr)>,
+    pub variant_destroy: ::std::option::Option<unsafe extern "C" fn(p_self: GDNativeVariantPtr)>,
+    pub variant_call: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeVariantPtr,
+            p_method: GDNativeStringNamePtr,
+            p_args: *const GDNativeVariantPtr,
+            p_argument_count: GDNativeInt,
+            r_return: GDNativeVariantPtr,
+            r_error: *mut GDNativeCallError,
+        ),
+    >,
+    pub variant_call_static: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_type: GDNativeVariantType,
+            p_method: GDNativeStringNamePtr,
+            p_args: *const GDNativeVariantPtr,
+            p_argument_count: GDNativeInt,
+            r_return: GDNativeVariantPtr,
+            r_error: *mut GDNativeCallError,
+        ),
+    >,
+    pub variant_evaluate: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_op: GDNativeVariantOperator,
+            p_a: GDNativeVariantPtr,
+            p_b: GDNativeVariantPtr,
+            r_return: GDNativeVariantPtr,
+            r_valid: *mut GDNativeBool,
+        ),
+    >,
+    pub variant_set: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeVariantPtr,
+            p_key: GDNativeVariantPtr,
+            p_value: GDNativeVariantPtr,
+            r_valid: *mut GDNativeBool,
+        ),
+    >,
+    pub variant_set_named: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeVariantPtr,
+            p_key: GDNativeStringNamePtr,
+            p_value: GDNativeVariantPtr,
+            r_valid: *mut GDNativeBool,
+        ),
+    >,
+    pub variant_set_keyed: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeVariantPtr,
+            p_key: GDNativeVariantPtr,
+            p_value: GDNativeVariantPtr,
+            r_valid: *mut GDNativeBool,
+        ),
+    >,
+    pub variant_set_indexed: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeVariantPtr,
+            p_index: GDNativeInt,
+            p_value: GDNativeVariantPtr,
+            r_valid: *mut GDNativeBool,
+            r_oob: *mut GDNativeBool,
+        ),
+    >,
+    pub variant_get: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeVariantPtr,
+            p_key: GDNativeVariantPtr,
+            r_ret: GDNativeVariantPtr,
+            r_valid: *mut GDNativeBool,
+        ),
+    >,
+    pub variant_get_named: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeVariantPtr,
+            p_key: GDNativeStringNamePtr,
+            r_ret: GDNativeVariantPtr,
+            r_valid: *mut GDNativeBool,
+        ),
+    >,
+    pub variant_get_keyed: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeVariantPtr,
+            p_key: GDNativeVariantPtr,
+            r_ret: GDNativeVariantPtr,
+            r_valid: *mut GDNativeBool,
+        ),
+    >,
+    pub variant_get_indexed: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeVariantPtr,
+            p_index: GDNativeInt,
+            r_ret: GDNativeVariantPtr,
+            r_valid: *mut GDNativeBool,
+            r_oob: *mut GDNativeBool,
+        ),
+    >,
+    pub variant_iter_init: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeVariantPtr,
+            r_iter: GDNativeVariantPtr,
+            r_valid: *mut GDNativeBool,
+        ) -> GDNativeBool,
+    >,
+    pub variant_iter_next: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeVariantPtr,
+            r_iter: GDNativeVariantPtr,
+            r_valid: *mut GDNativeBool,
+        ) -> GDNativeBool,
+    >,
+    pub variant_iter_get: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeVariantPtr,
+            r_iter: GDNativeVariantPtr,
+            r_ret: GDNativeVariantPtr,
+            r_valid: *mut GDNativeBool,
+        ),
+    >,
+    pub variant_hash_compare: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeVariantPtr, p_other: GDNativeVariantPtr) -> GDNativeBool,
+    >,
+    pub variant_booleanize:
+        ::std::option::Option<unsafe extern "C" fn(p_self: GDNativeVariantPtr) -> GDNativeBool>,
+    pub variant_blend: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_a: GDNativeVariantPtr,
+            p_b: GDNativeVariantPtr,
+            p_c: f32,
+            r_dst: GDNativeVariantPtr,
+        ),
+    >,
+    pub variant_interpolate: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_a: GDNativeVariantPtr,
+            p_b: GDNativeVariantPtr,
+            p_c: f32,
+            r_dst: GDNativeVariantPtr,
+        ),
+    >,
+    pub variant_duplicate: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeVariantPtr, r_ret: GDNativeVariantPtr, p_deep: GDNativeBool),
+    >,
+    pub variant_stringify:
+        ::std::option::Option<unsafe extern "C" fn(p_self: GDNativeVariantPtr, r_ret: GDNativeStringPtr)>,
+    pub variant_get_type:
+        ::std::option::Option<unsafe extern "C" fn(p_self: GDNativeVariantPtr) -> GDNativeVariantType>,
+    pub variant_has_method: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeVariantPtr, p_method: GDNativeStringNamePtr) -> GDNativeBool,
+    >,
+    pub variant_has_member: ::std::option::Option<
+        unsafe extern "C" fn(p_type: GDNativeVariantType, p_member: GDNativeStringNamePtr) -> GDNativeBool,
+    >,
+    pub variant_has_key: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeVariantPtr,
+            p_key: GDNativeVariantPtr,
+            r_valid: *mut GDNativeBool,
+        ) -> GDNativeBool,
+    >,
+    pub variant_get_type_name:
+        ::std::option::Option<unsafe extern "C" fn(p_type: GDNativeVariantType, r_name: GDNativeStringPtr)>,
+    pub variant_can_convert: ::std::option::Option<
+        unsafe extern "C" fn(p_from: GDNativeVariantType, p_to: GDNativeVariantType) -> GDNativeBool,
+    >,
+    pub variant_can_convert_strict: ::std::option::Option<
+        unsafe extern "C" fn(p_from: GDNativeVariantType, p_to: GDNativeVariantType) -> GDNativeBool,
+    >,
+    pub get_variant_from_type_constructor: ::std::option::Option<
+        unsafe extern "C" fn(p_type: GDNativeVariantType) -> GDNativeVariantFromTypeConstructorFunc,
+    >,
+    pub get_variant_to_type_constructor: ::std::option::Option<
+        unsafe extern "C" fn(p_type: GDNativeVariantType) -> GDNativeTypeFromVariantConstructorFunc,
+    >,
+    pub variant_get_ptr_operator_evaluator: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_operator: GDNativeVariantOperator,
+            p_type_a: GDNativeVariantType,
+            p_type_b: GDNativeVariantType,
+        ) -> GDNativePtrOperatorEvaluator,
+    >,
+    pub variant_get_ptr_builtin_method: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_type: GDNativeVariantType,
+            p_method: *const ::std::os::raw::c_char,
+            p_hash: GDNativeInt,
+        ) -> GDNativePtrBuiltInMethod,
+    >,
+    pub variant_get_ptr_constructor: ::std::option::Option<
+        unsafe extern "C" fn(p_type: GDNativeVariantType, p_constructor: i32) -> GDNativePtrConstructor,
+    >,
+    pub variant_get_ptr_destructor:
+        ::std::option::Option<unsafe extern "C" fn(p_type: GDNativeVariantType) -> GDNativePtrDestructor>,
+    pub variant_construct: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_type: GDNativeVariantType,
+            p_base: GDNativeVariantPtr,
+            p_args: *const GDNativeVariantPtr,
+            p_argument_count: i32,
+            r_error: *mut GDNativeCallError,
+        ),
+    >,
+    pub variant_get_ptr_setter: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_type: GDNativeVariantType,
+            p_member: *const ::std::os::raw::c_char,
+        ) -> GDNativePtrSetter,
+    >,
+    pub variant_get_ptr_getter: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_type: GDNativeVariantType,
+            p_member: *const ::std::os::raw::c_char,
+        ) -> GDNativePtrGetter,
+    >,
+    pub variant_get_ptr_indexed_setter:
+        ::std::option::Option<unsafe extern "C" fn(p_type: GDNativeVariantType) -> GDNativePtrIndexedSetter>,
+    pub variant_get_ptr_indexed_getter:
+        ::std::option::Option<unsafe extern "C" fn(p_type: GDNativeVariantType) -> GDNativePtrIndexedGetter>,
+    pub variant_get_ptr_keyed_setter:
+        ::std::option::Option<unsafe extern "C" fn(p_type: GDNativeVariantType) -> GDNativePtrKeyedSetter>,
+    pub variant_get_ptr_keyed_getter:
+        ::std::option::Option<unsafe extern "C" fn(p_type: GDNativeVariantType) -> GDNativePtrKeyedGetter>,
+    pub variant_get_ptr_keyed_checker:
+        ::std::option::Option<unsafe extern "C" fn(p_type: GDNativeVariantType) -> GDNativePtrKeyedChecker>,
+    pub variant_get_constant_value: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_type: GDNativeVariantType,
+            p_constant: *const ::std::os::raw::c_char,
+            r_ret: GDNativeVariantPtr,
+        ),
+    >,
+    pub variant_get_ptr_utility_function: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_function: *const ::std::os::raw::c_char,
+            p_hash: GDNativeInt,
+        ) -> GDNativePtrUtilityFunction,
+    >,
+    pub string_new_with_latin1_chars: ::std::option::Option<
+        unsafe extern "C" fn(r_dest: GDNativeStringPtr, p_contents: *const ::std::os::raw::c_char),
+    >,
+    pub string_new_with_utf8_chars: ::std::option::Option<
+        unsafe extern "C" fn(r_dest: GDNativeStringPtr, p_contents: *const ::std::os::raw::c_char),
+    >,
+    pub string_new_with_utf16_chars:
+        ::std::option::Option<unsafe extern "C" fn(r_dest: GDNativeStringPtr, p_contents: *const char16_t)>,
+    pub string_new_with_utf32_chars:
+        ::std::option::Option<unsafe extern "C" fn(r_dest: GDNativeStringPtr, p_contents: *const char32_t)>,
+    pub string_new_with_wide_chars:
+        ::std::option::Option<unsafe extern "C" fn(r_dest: GDNativeStringPtr, p_contents: *const wchar_t)>,
+    pub string_new_with_latin1_chars_and_len: ::std::option::Option<
+        unsafe extern "C" fn(
+            r_dest: GDNativeStringPtr,
+            p_contents: *const ::std::os::raw::c_char,
+            p_size: GDNativeInt,
+        ),
+    >,
+    pub string_new_with_utf8_chars_and_len: ::std::option::Option<
+        unsafe extern "C" fn(
+            r_dest: GDNativeStringPtr,
+            p_contents: *const ::std::os::raw::c_char,
+            p_size: GDNativeInt,
+        ),
+    >,
+    pub string_new_with_utf16_chars_and_len: ::std::option::Option<
+        unsafe extern "C" fn(r_dest: GDNativeStringPtr, p_contents: *const char16_t, p_size: GDNativeInt),
+    >,
+    pub string_new_with_utf32_chars_and_len: ::std::option::Option<
+        unsafe extern "C" fn(r_dest: GDNativeStringPtr, p_contents: *const char32_t, p_size: GDNativeInt),
+    >,
+    pub string_new_with_wide_chars_and_len: ::std::option::Option<
+        unsafe extern "C" fn(r_dest: GDNativeStringPtr, p_contents: *const wchar_t, p_size: GDNativeInt),
+    >,
+    pub string_to_latin1_chars: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeStringPtr,
+            r_text: *mut ::std::os::raw::c_char,
+            p_max_write_length: GDNativeInt,
+        ) -> GDNativeInt,
+    >,
+    pub string_to_utf8_chars: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeStringPtr,
+            r_text: *mut ::std::os::raw::c_char,
+            p_max_write_length: GDNativeInt,
+        ) -> GDNativeInt,
+    >,
+    pub string_to_utf16_chars: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeStringPtr,
+            r_text: *mut char16_t,
+            p_max_write_length: GDNativeInt,
+        ) -> GDNativeInt,
+    >,
+    pub string_to_utf32_chars: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeStringPtr,
+            r_text: *mut char32_t,
+            p_max_write_length: GDNativeInt,
+        ) -> GDNativeInt,
+    >,
+    pub string_to_wide_chars: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_self: GDNativeStringPtr,
+            r_text: *mut wchar_t,
+            p_max_write_length: GDNativeInt,
+        ) -> GDNativeInt,
+    >,
+    pub string_operator_index: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeStringPtr, p_index: GDNativeInt) -> *mut char32_t,
+    >,
+    pub string_operator_index_const: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeStringPtr, p_index: GDNativeInt) -> *const char32_t,
+    >,
+    pub packed_byte_array_operator_index:
+        ::std::option::Option<unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> *mut u8>,
+    pub packed_byte_array_operator_index_const:
+        ::std::option::Option<unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> *const u8>,
+    pub packed_color_array_operator_index: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> GDNativeTypePtr,
+    >,
+    pub packed_color_array_operator_index_const: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> GDNativeTypePtr,
+    >,
+    pub packed_float32_array_operator_index:
+        ::std::option::Option<unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> *mut f32>,
+    pub packed_float32_array_operator_index_const: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> *const f32,
+    >,
+    pub packed_float64_array_operator_index:
+        ::std::option::Option<unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> *mut f64>,
+    pub packed_float64_array_operator_index_const: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> *const f64,
+    >,
+    pub packed_int32_array_operator_index:
+        ::std::option::Option<unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> *mut i32>,
+    pub packed_int32_array_operator_index_const: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> *const i32,
+    >,
+    pub packed_int64_array_operator_index:
+        ::std::option::Option<unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> *mut i64>,
+    pub packed_int64_array_operator_index_const: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> *const i64,
+    >,
+    pub packed_string_array_operator_index: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> GDNativeStringPtr,
+    >,
+    pub packed_string_array_operator_index_const: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> GDNativeStringPtr,
+    >,
+    pub packed_vector2_array_operator_index: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> GDNativeTypePtr,
+    >,
+    pub packed_vector2_array_operator_index_const: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> GDNativeTypePtr,
+    >,
+    pub packed_vector3_array_operator_index: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> GDNativeTypePtr,
+    >,
+    pub packed_vector3_array_operator_index_const: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> GDNativeTypePtr,
+    >,
+    pub array_operator_index: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> GDNativeVariantPtr,
+    >,
+    pub array_operator_index_const: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_index: GDNativeInt) -> GDNativeVariantPtr,
+    >,
+    pub dictionary_operator_index: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_key: GDNativeVariantPtr) -> GDNativeVariantPtr,
+    >,
+    pub dictionary_operator_index_const: ::std::option::Option<
+        unsafe extern "C" fn(p_self: GDNativeTypePtr, p_key: GDNativeVariantPtr) -> GDNativeVariantPtr,
+    >,
+    pub object_method_bind_call: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_method_bind: GDNativeMethodBindPtr,
+            p_instance: GDNativeObjectPtr,
+            p_args: *const GDNativeVariantPtr,
+            p_arg_count: GDNativeInt,
+            r_ret: GDNativeVariantPtr,
+            r_error: *mut GDNativeCallError,
+        ),
+    >,
+    pub object_method_bind_ptrcall: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_method_bind: GDNativeMethodBindPtr,
+            p_instance: GDNativeObjectPtr,
+            p_args: *const GDNativeTypePtr,
+            r_ret: GDNativeTypePtr,
+        ),
+    >,
+    pub object_destroy: ::std::option::Option<unsafe extern "C" fn(p_o: GDNativeObjectPtr)>,
+    pub global_get_singleton: ::std::option::Option<
+        unsafe extern "C" fn(p_name: *const ::std::os::raw::c_char) -> GDNativeObjectPtr,
+    >,
+    pub object_get_instance_binding: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_o: GDNativeObjectPtr,
+            p_token: *mut ::std::os::raw::c_void,
+            p_callbacks: *const GDNativeInstanceBindingCallbacks,
+        ) -> *mut ::std::os::raw::c_void,
+    >,
+    pub object_set_instance_binding: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_o: GDNativeObjectPtr,
+            p_token: *mut ::std::os::raw::c_void,
+            p_binding: *mut ::std::os::raw::c_void,
+            p_callbacks: *const GDNativeInstanceBindingCallbacks,
+        ),
+    >,
+    pub object_set_instance: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_o: GDNativeObjectPtr,
+            p_classname: *const ::std::os::raw::c_char,
+            p_instance: GDExtensionClassInstancePtr,
+        ),
+    >,
+    pub object_cast_to: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_object: GDNativeObjectPtr,
+            p_class_tag: *mut ::std::os::raw::c_void,
+        ) -> GDNativeObjectPtr,
+    >,
+    pub object_get_instance_from_id:
+        ::std::option::Option<unsafe extern "C" fn(p_instance_id: GDObjectInstanceID) -> GDNativeObjectPtr>,
+    pub object_get_instance_id:
+        ::std::option::Option<unsafe extern "C" fn(p_object: GDNativeObjectPtr) -> GDObjectInstanceID>,
+    pub classdb_get_constructor: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_classname: *const ::std::os::raw::c_char,
+            r_extension: *mut GDNativeExtensionPtr,
+        ) -> GDNativeClassConstructor,
+    >,
+    pub classdb_construct_object: ::std::option::Option<
+        unsafe extern "C" fn(p_classname: *const ::std::os::raw::c_char) -> GDNativeObjectPtr,
+    >,
+    pub classdb_get_method_bind: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_classname: *const ::std::os::raw::c_char,
+            p_methodname: *const ::std::os::raw::c_char,
+            p_hash: GDNativeInt,
+        ) -> GDNativeMethodBindPtr,
+    >,
+    pub classdb_get_class_tag: ::std::option::Option<
+        unsafe extern "C" fn(p_classname: *const ::std::os::raw::c_char) -> *mut ::std::os::raw::c_void,
+    >,
+    pub classdb_register_extension_class: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_library: GDNativeExtensionClassLibraryPtr,
+            p_class_name: *const ::std::os::raw::c_char,
+            p_parent_class_name: *const ::std::os::raw::c_char,
+            p_extension_funcs: *const GDNativeExtensionClassCreationInfo,
+        ),
+    >,
+    pub classdb_register_extension_class_method: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_library: GDNativeExtensionClassLibraryPtr,
+            p_class_name: *const ::std::os::raw::c_char,
+            p_method_info: *const GDNativeExtensionClassMethodInfo,
+        ),
+    >,
+    pub classdb_register_extension_class_integer_constant: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_library: GDNativeExtensionClassLibraryPtr,
+            p_class_name: *const ::std::os::raw::c_char,
+            p_enum_name: *const ::std::os::raw::c_char,
+            p_constant_name: *const ::std::os::raw::c_char,
+            p_constant_value: GDNativeInt,
+        ),
+    >,
+    pub classdb_register_extension_class_property: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_library: GDNativeExtensionClassLibraryPtr,
+            p_class_name: *const ::std::os::raw::c_char,
+            p_info: *const GDNativePropertyInfo,
+            p_setter: *const ::std::os::raw::c_char,
+            p_getter: *const ::std::os::raw::c_char,
+        ),
+    >,
+    pub classdb_register_extension_class_property_group: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_library: GDNativeExtensionClassLibraryPtr,
+            p_class_name: *const ::std::os::raw::c_char,
+            p_group_name: *const ::std::os::raw::c_char,
+            p_prefix: *const ::std::os::raw::c_char,
+        ),
+    >,
+    pub classdb_register_extension_class_property_subgroup: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_library: GDNativeExtensionClassLibraryPtr,
+            p_class_name: *const ::std::os::raw::c_char,
+            p_subgroup_name: *const ::std::os::raw::c_char,
+            p_prefix: *const ::std::os::raw::c_char,
+        ),
+    >,
+    pub classdb_register_extension_class_signal: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_library: GDNativeExtensionClassLibraryPtr,
+            p_class_name: *const ::std::os::raw::c_char,
+            p_signal_name: *const ::std::os::raw::c_char,
+            p_argument_info: *const GDNativePropertyInfo,
+            p_argument_count: GDNativeInt,
+        ),
+    >,
+    pub classdb_unregister_extension_class: ::std::option::Option<
+        unsafe extern "C" fn(
+            p_library: GDNativeExtensionClassLibraryPtr,
+            p_class_name: *const ::std::os::raw::c_char,
+        ),
+    >,
+}
+pub const GDNativeInitializationLevel_GDNATIVE_INITIALIZATION_CORE: GDNativeInitializationLevel = 0;
+pub const GDNativeInitializationLevel_GDNATIVE_INITIALIZATION_SERVERS: GDNativeInitializationLevel = 1;
+pub const GDNativeInitializationLevel_GDNATIVE_INITIALIZATION_SCENE: GDNativeInitializationLevel = 2;
+pub const GDNativeInitializationLevel_GDNATIVE_INITIALIZATION_DRIVER: GDNativeInitializationLevel = 3;
+pub const GDNativeInitializationLevel_GDNATIVE_INITIALIZATION_EDITOR: GDNativeInitializationLevel = 4;
+pub const GDNativeInitializationLevel_GDNATIVE_MAX_INITIALIZATION_LEVEL: GDNativeInitializationLevel = 5;
+pub type GDNativeInitializationLevel = ::std::os::raw::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GDNativeInitialization {
+    pub minimum_initialization_level: GDNativeInitializationLevel,
+    pub userdata: *mut ::std::os::raw::c_void,
+    pub initialize: ::std::option::Option<
+        unsafe extern "C" fn(userdata: *mut ::std::os::raw::c_void, p_level: GDNativeInitializationLevel),
+    >,
+    pub deinitialize: ::std::option::Option<
+        unsafe extern "C" fn(userdata: *mut ::std::os::raw::c_void, p_level: GDNativeInitializationLevel),
+    >,
+}
+pub type GDNativeInitializationFunction = ::std::option::Option<
+    unsafe extern "C" fn(
+        p_interface: *const GDNativeInterface,
+        p_library: GDNativeExtensionClassLibraryPtr,
+        r_initialization: *mut GDNativeInitialization,
+    ),
+>;
@@ -1,17 +1,79 @@
-use std::{env, path::PathBuf};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Overrides the header the bindings are generated from, e.g. for a custom engine build. Requires
+/// the `bindgen` feature.
+const HEADER_ENV: &str = "GDEXT_GDNATIVE_INTERFACE_H";
+
+const DEFAULT_HEADER: &str = "../thirdparty/godot-headers/godot/gdnative_interface.h";
+
+/// The Godot version of the submodule's header and of the vendored bindings, which live in
+/// `bindings/<version>`. Keep in sync with `GODOT_API_VERSION` in `src/lib.rs`.
+const BINDINGS_VERSION: &str = "4.0";
 
 fn main() {
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("gdnative_interface.rs");
+
+    println!("cargo:rerun-if-env-changed={}", HEADER_ENV);
+    let header = env::var_os(HEADER_ENV).map(PathBuf::from);
+
+    if cfg!(feature = "bindgen") {
+        let header = header.unwrap_or_else(|| PathBuf::from(DEFAULT_HEADER));
+        generate_bindings(&header, &out_path);
+    } else {
+        if let Some(header) = header {
+            panic!(
+                "{} is set to {}, but generating bindings requires the `bindgen` feature of gdext-sys",
+                HEADER_ENV,
+                header.display()
+            );
+        }
+        copy_vendored_bindings(&out_path);
+    }
+}
+
+/// Copies the pre-generated bindings matching the target platform.
+fn copy_vendored_bindings(out_path: &Path) {
+    // C enums are signed with MSVC and `wchar_t` is 16 bits on Windows; everything else is shared.
+    let platform = match env::var("CARGO_CFG_TARGET_OS").unwrap().as_str() {
+        "windows" => "windows",
+        _ => "unix",
+    };
+    let file = format!(
+        "bindings/{}/gdnative_interface_{}.rs",
+        BINDINGS_VERSION, platform
+    );
+    println!("cargo:rerun-if-changed={}", file);
+
+    fs::copy(&file, out_path).expect("could not copy the vendored gdnative_interface bindings");
+}
+
+#[cfg(feature = "bindgen")]
+fn generate_bindings(header: &Path, out_path: &Path) {
+    if !header.exists() {
+        panic!(
+            "{} does not exist; check out the godot-headers submodule or set {}",
+            header.display(),
+            HEADER_ENV
+        );
+    }
+
     let bindings = bindgen::Builder::default()
-        .header("../thirdparty/godot-headers/godot/gdnative_interface.h")
+        .header(header.to_string_lossy())
         // Tell cargo to invalidate the built crate whenever any of the
         // included header files changed.
         .parse_callbacks(Box::new(bindgen::CargoCallbacks))
         .generate()
         .expect("unable to generate gdnative_interface.h bindings");
 
-    // Write the bindings to the $OUT_DIR/bindings.rs file.
-    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
     bindings
-        .write_to_file(out_path.join("gdnative_interface.rs"))
+        .write_to_file(out_path)
         .expect("could not write gdnative_interface Rust bindings!");
 }
+
+#[cfg(not(feature = "bindgen"))]
+fn generate_bindings(_header: &Path, _out_path: &Path) {
+    unreachable!()
+}
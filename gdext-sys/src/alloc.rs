@@ -0,0 +1,202 @@
+//! A global allocator going through Godot's `mem_alloc`, `mem_realloc` and `mem_free`, so that the
+//! memory used by Rust shows up in Godot's memory statistics (which only debug builds of Godot
+//! keep). Opt in with:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: gdext_sys::alloc::GodotAllocator = gdext_sys::alloc::GodotAllocator;
+//! ```
+//!
+//! Before [`set_interface`](crate::set_interface) is called, and for alignments Godot doesn't
+//! guarantee, memory comes from the system allocator instead. Each allocation records where it
+//! comes from, so that it's always returned to the right allocator.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    mem, ptr,
+};
+
+use crate::try_get_interface as interface;
+
+/// The alignment of Godot's allocations, which are `malloc`'s offset by 16 bytes.
+const GODOT_ALIGN: usize = 2 * mem::size_of::<usize>();
+
+/// The room before each allocation, holding its origin in the last word.
+const HEADER: usize = 16;
+
+const FROM_GODOT: usize = 0x60d0_a110;
+const FROM_SYSTEM: usize = 0x5157_a110;
+
+/// Allocates through Godot once the interface is set, see the [module documentation](self).
+pub struct GodotAllocator;
+
+/// The bytes reserved before an allocation of `layout`.
+fn header_size(layout: Layout) -> usize {
+    layout.align().max(HEADER)
+}
+
+unsafe fn origin(ptr: *mut u8) -> *mut usize {
+    ptr.sub(mem::size_of::<usize>()) as *mut usize
+}
+
+unsafe impl GlobalAlloc for GodotAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let header = header_size(layout);
+        let Some(size) = layout.size().checked_add(header) else {
+            return ptr::null_mut();
+        };
+
+        if layout.align() <= GODOT_ALIGN {
+            if let Some(mem_alloc) = interface().and_then(|interface| interface.mem_alloc) {
+                let base = mem_alloc(size) as *mut u8;
+                if base.is_null() {
+                    return base;
+                }
+                let ptr = base.add(header);
+                *origin(ptr) = FROM_GODOT;
+                return ptr;
+            }
+        }
+
+        let Ok(system_layout) = Layout::from_size_align(size, layout.align().max(HEADER)) else {
+            return ptr::null_mut();
+        };
+        let base = System.alloc(system_layout);
+        if base.is_null() {
+            return base;
+        }
+        let ptr = base.add(header);
+        *origin(ptr) = FROM_SYSTEM;
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let header = header_size(layout);
+        let base = ptr.sub(header);
+
+        match *origin(ptr) {
+            FROM_GODOT => {
+                // Without the interface, Godot is gone along with its memory.
+                if let Some(mem_free) = interface().and_then(|interface| interface.mem_free) {
+                    mem_free(base as *mut _);
+                }
+            }
+            origin => {
+                debug_assert_eq!(origin, FROM_SYSTEM, "deallocating a corrupted allocation");
+                let system_layout = Layout::from_size_align_unchecked(
+                    layout.size() + header,
+                    layout.align().max(HEADER),
+                );
+                System.dealloc(base, system_layout);
+            }
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if *origin(ptr) == FROM_GODOT {
+            let header = header_size(layout);
+            let mem_realloc = interface().and_then(|interface| interface.mem_realloc);
+            if let (Some(mem_realloc), Some(size)) = (mem_realloc, new_size.checked_add(header)) {
+                let base = mem_realloc(ptr.sub(header) as *mut _, size) as *mut u8;
+                return if base.is_null() {
+                    base
+                } else {
+                    // The header moved along with the data.
+                    base.add(header)
+                };
+            }
+        }
+
+        let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+        let new_ptr = self.alloc(new_layout);
+        if !new_ptr.is_null() {
+            ptr::copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+            self.dealloc(ptr, layout);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Without the interface, as in these tests, everything goes to the system allocator.
+
+    unsafe fn check_block(ptr: *mut u8, layout: Layout) {
+        assert!(!ptr.is_null());
+        assert_eq!(
+            ptr as usize % layout.align(),
+            0,
+            "misaligned for {:?}",
+            layout
+        );
+        assert_eq!(*origin(ptr), FROM_SYSTEM);
+    }
+
+    #[test]
+    fn header_size_covers_the_alignment() {
+        let size = |align| header_size(Layout::from_size_align(8, align).unwrap());
+        assert_eq!(size(1), HEADER);
+        assert_eq!(size(16), HEADER);
+        assert_eq!(size(64), 64);
+        assert_eq!(size(4096), 4096);
+    }
+
+    #[test]
+    fn allocates_from_the_system() {
+        for align in [1, 8, 16, 32, 64, 4096] {
+            for size in [1, 7, 64, 1000] {
+                let layout = Layout::from_size_align(size, align).unwrap();
+                unsafe {
+                    let ptr = GodotAllocator.alloc(layout);
+                    check_block(ptr, layout);
+                    ptr::write_bytes(ptr, 0xab, size);
+                    GodotAllocator.dealloc(ptr, layout);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn zeroed_allocations_are_zeroed() {
+        let layout = Layout::from_size_align(100, 64).unwrap();
+        unsafe {
+            let ptr = GodotAllocator.alloc_zeroed(layout);
+            check_block(ptr, layout);
+            assert!(std::slice::from_raw_parts(ptr, 100).iter().all(|&b| b == 0));
+            GodotAllocator.dealloc(ptr, layout);
+        }
+    }
+
+    #[test]
+    fn realloc_keeps_contents_and_alignment() {
+        for align in [8, 64, 4096] {
+            let layout = Layout::from_size_align(16, align).unwrap();
+            unsafe {
+                let ptr = GodotAllocator.alloc(layout);
+                for i in 0..16 {
+                    *ptr.add(i) = i as u8;
+                }
+
+                let grown = GodotAllocator.realloc(ptr, layout, 1000);
+                let grown_layout = Layout::from_size_align(1000, align).unwrap();
+                check_block(grown, grown_layout);
+                assert!((0..16).all(|i| *grown.add(i) == i as u8));
+
+                let shrunk = GodotAllocator.realloc(grown, grown_layout, 4);
+                let shrunk_layout = Layout::from_size_align(4, align).unwrap();
+                check_block(shrunk, shrunk_layout);
+                assert!((0..4).all(|i| *shrunk.add(i) == i as u8));
+
+                GodotAllocator.dealloc(shrunk, shrunk_layout);
+            }
+        }
+    }
+
+    #[test]
+    fn oversized_allocations_fail() {
+        let layout = Layout::from_size_align(isize::MAX as usize - 8, 8).unwrap();
+        assert!(unsafe { GodotAllocator.alloc(layout) }.is_null());
+    }
+}
@@ -0,0 +1,70 @@
+//! A lazily initialised value that can be invalidated when the extension is unloaded.
+//!
+//! Works like `once_cell::sync::Lazy`, for caching function pointers looked up through the
+//! interface. As those are only valid while the extension is loaded, [`reset_all`] invalidates
+//! every cached value at once, and they are looked up again on next use, e.g. after the editor
+//! reloaded the extension.
+
+use std::{
+    cell::UnsafeCell,
+    ops::Deref,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, PoisonError,
+    },
+};
+
+/// Incremented by [`reset_all`]; a value is valid if it was initialised in the current
+/// generation. Starts at 1, so that 0 can mean "never initialised".
+static GENERATION: AtomicUsize = AtomicUsize::new(1);
+
+pub struct Lazy<T> {
+    init: fn() -> T,
+    generation: AtomicUsize,
+    lock: Mutex<()>,
+    value: UnsafeCell<Option<T>>,
+}
+
+// The value is only written while holding `lock`, before `generation` is published.
+unsafe impl<T: Send + Sync> Sync for Lazy<T> {}
+
+impl<T> Lazy<T> {
+    pub const fn new(init: fn() -> T) -> Self {
+        Self {
+            init,
+            generation: AtomicUsize::new(0),
+            lock: Mutex::new(()),
+            value: UnsafeCell::new(None),
+        }
+    }
+}
+
+impl<T> Deref for Lazy<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        let current = GENERATION.load(Ordering::Acquire);
+
+        if self.generation.load(Ordering::Acquire) != current {
+            let _guard = self.lock.lock().unwrap_or_else(PoisonError::into_inner);
+            if self.generation.load(Ordering::Acquire) != current {
+                unsafe {
+                    *self.value.get() = Some((self.init)());
+                }
+                self.generation.store(current, Ordering::Release);
+            }
+        }
+
+        unsafe { (*self.value.get()).as_ref().unwrap() }
+    }
+}
+
+/// Invalidates all [`Lazy`] values, so that they are initialised again on next use.
+///
+/// # Safety
+///
+/// Must not be called while a reference obtained from a [`Lazy`] is still in use, e.g. only once
+/// the extension has been deinitialised.
+pub unsafe fn reset_all() {
+    GENERATION.fetch_add(1, Ordering::AcqRel);
+}
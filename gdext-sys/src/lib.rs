@@ -7,60 +7,146 @@
     clippy::redundant_static_lifetimes
 )]
 
-use std::mem::MaybeUninit;
+use std::{
+    ffi::c_void,
+    ptr,
+    sync::{
+        atomic::{AtomicPtr, Ordering},
+        OnceLock,
+    },
+    thread::{self, ThreadId},
+};
 
 include!(concat!(env!("OUT_DIR"), "/gdnative_interface.rs"));
 
+pub mod alloc;
+pub mod lazy;
+
+/// The `(major, minor)` Godot version of the vendored bindings and the godot-headers submodule.
+/// Bindings generated from another header (see `GDEXT_GDNATIVE_INTERFACE_H`) must be for this
+/// version too.
+pub const GODOT_API_VERSION: (u32, u32) = (4, 0);
+
 #[allow(non_camel_case_types)]
-#[cfg(feature = "real_is_float")]
+#[cfg(not(feature = "real_is_double"))]
 pub type real = f32;
 #[allow(non_camel_case_types)]
 #[cfg(feature = "real_is_double")]
 pub type real = f64;
 
-static mut INTERFACE: MaybeUninit<GDNativeInterface> = MaybeUninit::uninit();
+static INTERFACE: AtomicPtr<GDNativeInterface> = AtomicPtr::new(ptr::null_mut());
+
+static LIBRARY: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
 
-static mut LIBRARY: MaybeUninit<GDNativeExtensionClassLibraryPtr> = MaybeUninit::uninit();
+static MAIN_THREAD: OnceLock<ThreadId> = OnceLock::new();
 
+/// Sets the interface, and records the calling thread as the main thread.
+///
 /// # Safety
 ///
-/// The `interface` pointer must be a valid pointer to a [`GDNativeInterface`] object.
+/// The `interface` pointer must be a valid pointer to a [`GDNativeInterface`] object, living as
+/// long as the library is loaded (as the one given by Godot at initialisation does).
 pub unsafe fn set_interface(interface: *const GDNativeInterface) {
-    INTERFACE = MaybeUninit::new(*interface);
+    INTERFACE.store(interface as *mut _, Ordering::Release);
+    let _ = MAIN_THREAD.set(thread::current().id());
 }
 
-/// # Safety
+/// Returns the interface, or `None` before [`set_interface`] is called, e.g. in unit tests
+/// running outside of Godot.
+#[inline]
+pub fn try_get_interface() -> Option<&'static GDNativeInterface> {
+    // Only ever set to null or a pointer living as long as the library, see `set_interface`.
+    unsafe { INTERFACE.load(Ordering::Acquire).as_ref() }
+}
+
+/// # Panics
 ///
-/// The interface must have been initialised with [`set_interface`] before calling this function.
+/// If called before [`set_interface`], i.e. outside of Godot.
 #[inline(always)]
-pub unsafe fn get_interface() -> &'static GDNativeInterface {
-    &*INTERFACE.as_ptr()
+#[track_caller]
+pub fn get_interface() -> &'static GDNativeInterface {
+    match try_get_interface() {
+        Some(interface) => interface,
+        None => not_initialized("interface"),
+    }
 }
 
 /// # Safety
 ///
-/// - The `library` pointer must be the pointer given by Godot at initialisation.
-/// - This function must not be called from multiple threads.
-/// - This funnction must be called before any use of [`get_library`].
+/// The `library` pointer must be the pointer given by Godot at initialisation.
 pub unsafe fn set_library(library: GDNativeExtensionClassLibraryPtr) {
-    LIBRARY = MaybeUninit::new(library);
+    LIBRARY.store(library, Ordering::Release);
 }
 
-/// # Safety
+/// Returns the library pointer, or `None` before [`set_library`] is called.
+#[inline]
+pub fn try_get_library() -> Option<GDNativeExtensionClassLibraryPtr> {
+    let library = LIBRARY.load(Ordering::Acquire);
+    (!library.is_null()).then_some(library)
+}
+
+/// # Panics
 ///
-/// The library must have been initialised with [`set_library`] before calling this function.
+/// If called before [`set_library`], i.e. outside of Godot.
 #[inline(always)]
-pub unsafe fn get_library() -> GDNativeExtensionClassLibraryPtr {
-    *LIBRARY.as_ptr()
+#[track_caller]
+pub fn get_library() -> GDNativeExtensionClassLibraryPtr {
+    match try_get_library() {
+        Some(library) => library,
+        None => not_initialized("library"),
+    }
+}
+
+/// Whether Godot initialised the library, so that the engine can be called into.
+pub fn is_initialized() -> bool {
+    try_get_interface().is_some() && try_get_library().is_some()
+}
+
+#[cold]
+#[track_caller]
+fn not_initialized(what: &str) -> ! {
+    panic!(
+        "Godot's {} used before the library was initialised (is this running outside of Godot?)",
+        what
+    )
+}
+
+#[doc(hidden)]
+#[cold]
+#[track_caller]
+pub fn missing_interface_fn(name: &str) -> ! {
+    panic!(
+        "Godot's interface has no `{}` function (is the engine older than the bindings?)",
+        name
+    )
+}
+
+/// Whether the calling thread is the one Godot initialised the library on, which runs the scene
+/// tree. Before initialisation, every thread counts as the main thread.
+pub fn is_main_thread() -> bool {
+    MAIN_THREAD
+        .get()
+        .is_none_or(|&main| main == thread::current().id())
+}
+
+/// Panics in debug builds unless called on the main thread, for engine calls that aren't
+/// thread-safe.
+#[inline]
+#[track_caller]
+pub fn debug_assert_main_thread() {
+    if cfg!(debug_assertions) && !is_main_thread() {
+        panic!("engine API that isn't thread-safe used off the main thread");
+    }
 }
 
+/// Returns the interface function `$name`, panicking with its name if Godot didn't provide it.
 #[macro_export]
 #[doc(hidden)]
 macro_rules! interface_fn {
     ($name:ident) => {{
-        fn unoption<T>(x: &Option<T>) -> &T {
-            unsafe { std::mem::transmute(x) }
+        match $crate::get_interface().$name {
+            Some(f) => f,
+            None => $crate::missing_interface_fn(stringify!($name)),
         }
-        (unoption(&$crate::get_interface().$name))
     }};
 }
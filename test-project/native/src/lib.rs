@@ -1,5 +1,5 @@
 use gdext_builtin::{
-    gdext_init, gdext_print_warning, string::GodotString, variant::Variant, vector2::Vector2,
+    gdext_print, gdext_print_warning, string::GodotString, variant::Variant, vector2::Vector2,
     vector3::Vector3, InitLevel,
 };
 use gdext_class::*;
@@ -27,6 +27,7 @@ impl GodotClass for Node3D {
     }
 }
 
+#[derive(RegisterClass)]
 pub struct RustTest {
     base: Node3D,
     time: f64,
@@ -55,6 +56,12 @@ impl GodotExtensionClass for RustTest {
             time: 0.0,
         }
     }
+
+    fn on_notification(&mut self, what: Notification) {
+        if what == Notification::EnterWorld {
+            gdext_print!("RustTest entered the 3D world");
+        }
+    }
 }
 
 impl RustTest {
@@ -112,8 +119,6 @@ impl GodotExtensionClassMethods for RustTest {
 
 gdext_init!(gdext_rust_test, |init: &mut gdext_builtin::InitOptions| {
     init.register_init_function(InitLevel::Scene, || {
-        register_class::<RustTest>();
-
         variant_tests();
     });
 });